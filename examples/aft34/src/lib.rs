@@ -28,7 +28,22 @@ pub mod my_aft34 {
 
         #[ink(message)]
         pub fn mint(&mut self, id: Id) -> Result<(), AFT34Error> {
-            aft34::Internal::_mint_to(self, Self::env().caller(), id)
+            aft34::Internal::_mint_to(self, Self::env().caller(), id)?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn mint_consecutive(&mut self, first_id: Id, amount: u128) -> Result<(), AFT34Error> {
+            aft34::Internal::_mint_consecutive(self, Self::env().caller(), first_id, amount)
+        }
+
+        #[ink(message)]
+        pub fn burn(&mut self, id: Id) -> Result<(), AFT34Error> {
+            let caller = Self::env().caller();
+            if aft34::Internal::_check_token_exists(self, &id)? != caller {
+                return Err(AFT34Error::NotApproved);
+            }
+            aft34::Internal::_burn_from(self, caller, id)
         }
     }
 
@@ -419,5 +434,199 @@ pub mod my_aft34 {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn consecutive_mint_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft34", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint_consecutive(Id::U64(1), 10_000));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint_consecutive failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_result, Ok(()));
+            assert_eq!(balance_of!(client, address, alice), 10_000);
+            assert_eq!(
+                owner_of!(client, address, Id::U64(5_000)),
+                Some(address_of!(alice))
+            );
+            assert_eq!(owner_of!(client, address, Id::U64(10_001)), None);
+            assert_eq!(owner_of!(client, address, Id::U128(5_000)), None);
+
+            let overlapping_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint_consecutive(Id::U64(10_000), 10));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                overlapping_result,
+                Err(AFT34Error::Custom(String::from("InvalidIdRange")))
+            );
+
+            let transfer_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(address_of!(bob), Id::U64(42), vec![]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            }
+            .return_value();
+
+            assert_eq!(transfer_result, Ok(()));
+            assert_eq!(
+                owner_of!(client, address, Id::U64(42)),
+                Some(address_of!(bob))
+            );
+
+            let burn_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.burn(Id::U64(43)));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("burn failed")
+            }
+            .return_value();
+
+            assert_eq!(burn_result, Ok(()));
+            assert_eq!(owner_of!(client, address, Id::U64(43)), None);
+            assert_eq!(balance_of!(client, address, alice), 9_998);
+            assert_eq!(balance_of!(client, address, bob), 1);
+
+            let single_mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(Id::U64(10_005)));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(single_mint_result, Ok(()));
+
+            let minted_id_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint_consecutive(Id::U64(10_001), 10));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(minted_id_result, Err(AFT34Error::TokenExists));
+
+            let u128_mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint_consecutive(Id::U128(1), 10));
+                client
+                    .call(&ink_e2e::bob(), _msg, 0, None)
+                    .await
+                    .expect("mint_consecutive failed")
+            }
+            .return_value();
+
+            assert_eq!(u128_mint_result, Ok(()));
+            assert_eq!(
+                owner_of!(client, address, Id::U128(5)),
+                Some(address_of!(bob))
+            );
+            assert_eq!(
+                owner_of!(client, address, Id::U64(5)),
+                Some(address_of!(alice))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn token_approval_is_replaced_and_cleared(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft34", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mint_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint_token());
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint_token failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_result, Ok(()));
+
+            for operator in [address_of!(bob), address_of!(charlie)] {
+                let approve_result = {
+                    let _msg = build_message::<ContractRef>(address.clone())
+                        .call(|contract| contract.approve(operator, Some(Id::U8(0)), true));
+                    client
+                        .call(&ink_e2e::alice(), _msg, 0, None)
+                        .await
+                        .expect("approve failed")
+                }
+                .return_value();
+
+                assert_eq!(approve_result, Ok(()));
+            }
+
+            let approved = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.get_approved(Id::U8(0)));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(approved, Some(address_of!(charlie)));
+
+            let bob_transfer_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(address_of!(bob), Id::U8(0), vec![]));
+                client.call_dry_run(&ink_e2e::bob(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(bob_transfer_result, Err(AFT34Error::NotApproved));
+
+            let transfer_result = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(address_of!(dave), Id::U8(0), vec![]));
+                client
+                    .call(&ink_e2e::charlie(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            }
+            .return_value();
+
+            assert_eq!(transfer_result, Ok(()));
+
+            let approved = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.get_approved(Id::U8(0)));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(approved, None);
+
+            Ok(())
+        }
     }
 }
@@ -12,6 +12,12 @@ pub mod my_aft34_enumerable {
         enumerable: enumerable::Data,
     }
 
+    // Keep pages small so that the page size cap can be observed in tests.
+    #[overrider(enumerable::Internal)]
+    fn _max_page_size(&self) -> u128 {
+        3
+    }
+
     impl Contract {
         #[ink(constructor)]
         pub fn new() -> Self {
@@ -237,5 +243,82 @@ pub mod my_aft34_enumerable {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn pagination_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate(
+                    "my_aft34_enumerable",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let ids: Vec<Id> = (1u8..=4).map(Id::U8).collect();
+
+            for id in &ids {
+                let mint_result = {
+                    let _msg = build_message::<ContractRef>(address.clone())
+                        .call(|contract| contract.mint(address_of!(bob), id.clone()));
+                    client
+                        .call(&ink_e2e::alice(), _msg, 0, None)
+                        .await
+                        .expect("call failed")
+                }
+                .return_value();
+
+                assert_eq!(mint_result, Ok(()));
+            }
+
+            let first_page = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.tokens_of_owner(address_of!(bob), 0, 10));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let second_page = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.tokens_of_owner(address_of!(bob), 3, 10));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            // `limit` is capped to the maximum page size
+            assert_eq!(first_page, ids[..3].to_vec());
+            assert_eq!(second_page, ids[3..].to_vec());
+
+            let tokens_page = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.tokens(2, 2));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let out_of_range_page = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.tokens(10, 2));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let empty_owner_page = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.tokens_of_owner(address_of!(alice), 0, 2));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(tokens_page, ids[2..].to_vec());
+            assert_eq!(out_of_range_page, vec![]);
+            assert_eq!(empty_owner_page, vec![]);
+
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,141 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(AccessControl)]
+#[allfeat_contracts::implementation(AFT34, AFT34URIStorage, AFT34LazyMint)]
+#[allfeat_contracts::contract]
+pub mod my_aft34_lazy_mint {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft34: aft34::Data,
+        #[storage_field]
+        uri_storage: uri_storage::Data,
+        #[storage_field]
+        lazy_mint: lazy_mint::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            access_control::Internal::_init_with_admin(&mut instance, Some(caller));
+            instance
+        }
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use allfeat_contracts::aft34::{
+            extensions::lazy_mint::{signing, MINTER},
+            AFT34Error::*,
+        };
+        use ink::env::test;
+
+        #[rustfmt::skip]
+        use super::*;
+
+        const MINTER_KEY: [u8; 32] = [1; 32];
+        const PRICE: Balance = 1_000;
+
+        #[ink::test]
+        fn redeem_works() {
+            let mut aft34 = Contract::new();
+            let accounts = test::default_accounts::<Environment>();
+            let contract = test::callee::<Environment>();
+            let minter = signing::minter_account(&MINTER_KEY);
+
+            let voucher = Voucher {
+                id: Id::U8(1),
+                uri: String::from("ipfs://song.json"),
+                price: PRICE,
+                recipient: None,
+                expiry: 1_000,
+                nonce: 0,
+            };
+            let signature = signing::sign_voucher(&MINTER_KEY, &contract, &voucher);
+            assert_eq!(
+                AFT34LazyMintImpl::voucher_hash(&aft34, voucher.clone()),
+                lazy_mint::voucher_hash(&contract, &voucher)
+            );
+
+            test::set_caller::<Environment>(accounts.bob);
+            test::set_value_transferred::<Environment>(PRICE);
+            assert_eq!(
+                AFT34LazyMintImpl::redeem(&mut aft34, accounts.bob, voucher.clone(), signature),
+                Err(Custom(String::from("AC::MissingRole")))
+            );
+
+            test::set_caller::<Environment>(accounts.alice);
+            assert!(AccessControlImpl::grant_role(&mut aft34, MINTER, Some(minter)).is_ok());
+
+            test::set_caller::<Environment>(accounts.bob);
+            test::set_account_balance::<Environment>(contract, PRICE);
+            assert!(AFT34LazyMintImpl::redeem(
+                &mut aft34,
+                accounts.bob,
+                voucher.clone(),
+                signature
+            )
+            .is_ok());
+            assert_eq!(AFT34Impl::owner_of(&aft34, Id::U8(1)), Some(accounts.bob));
+            assert_eq!(
+                AFT34URIStorageImpl::token_uri(&aft34, Id::U8(1)),
+                Ok(Some(String::from("ipfs://song.json")))
+            );
+            assert_eq!(test::get_account_balance::<Environment>(minter), Ok(PRICE));
+            assert!(AFT34LazyMintImpl::is_nonce_used(&aft34, minter, 0));
+
+            assert_eq!(
+                AFT34LazyMintImpl::redeem(&mut aft34, accounts.bob, voucher, signature),
+                Err(Custom(String::from("VoucherAlreadyRedeemed")))
+            );
+        }
+
+        #[ink::test]
+        fn invalid_vouchers_are_rejected() {
+            let mut aft34 = Contract::new();
+            let accounts = test::default_accounts::<Environment>();
+            let contract = test::callee::<Environment>();
+            assert!(AccessControlImpl::grant_role(
+                &mut aft34,
+                MINTER,
+                Some(signing::minter_account(&MINTER_KEY))
+            )
+            .is_ok());
+
+            let voucher = Voucher {
+                id: Id::U8(1),
+                uri: String::from("ipfs://song.json"),
+                price: 0,
+                recipient: Some(accounts.charlie),
+                expiry: 1_000,
+                nonce: 0,
+            };
+            let signature = signing::sign_voucher(&MINTER_KEY, &contract, &voucher);
+
+            assert_eq!(
+                AFT34LazyMintImpl::redeem(&mut aft34, accounts.bob, voucher.clone(), signature),
+                Err(Custom(String::from("InvalidRecipient")))
+            );
+
+            let mut tampered = voucher.clone();
+            tampered.uri = String::from("ipfs://another_song.json");
+            assert_eq!(
+                AFT34LazyMintImpl::redeem(&mut aft34, accounts.charlie, tampered, signature),
+                Err(Custom(String::from("AC::MissingRole")))
+            );
+
+            test::set_block_timestamp::<Environment>(1_001);
+            assert_eq!(
+                AFT34LazyMintImpl::redeem(&mut aft34, accounts.charlie, voucher, signature),
+                Err(Custom(String::from("VoucherExpired")))
+            );
+        }
+    }
+}
@@ -22,11 +22,33 @@ pub mod my_aft34_metadata {
 
             let name_key = String::from("name");
             let symbol_key = String::from("symbol");
-            metadata::Internal::_set_attribute(&mut instance, id.clone(), name_key, name);
-            metadata::Internal::_set_attribute(&mut instance, id, symbol_key, symbol);
+            metadata::Internal::_set_attribute(&mut instance, id.clone(), name_key, name)
+                .expect("Should set name");
+            metadata::Internal::_set_attribute(&mut instance, id, symbol_key, symbol)
+                .expect("Should set symbol");
 
             instance
         }
+
+        #[ink(message)]
+        pub fn set_attribute(
+            &mut self,
+            id: Id,
+            key: String,
+            value: String,
+        ) -> Result<(), AFT34Error> {
+            metadata::Internal::_set_attribute(self, id, key, value)
+        }
+
+        #[ink(message)]
+        pub fn remove_attribute(&mut self, id: Id, key: String) -> Result<(), AFT34Error> {
+            metadata::Internal::_remove_attribute(self, id, key)
+        }
+
+        #[ink(message)]
+        pub fn freeze_metadata(&mut self, id: Id) {
+            metadata::Internal::_freeze_metadata(self, id)
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -74,5 +96,108 @@ pub mod my_aft34_metadata {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn remove_attribute_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let id = Id::U8(0);
+            let name = String::from("My AFT34");
+            let symbol = String::from("MFT34");
+
+            let constructor = ContractRef::new(id.clone(), name.clone(), symbol.clone());
+            let address = client
+                .instantiate("my_aft34_metadata", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let result_attributes = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.get_attributes(id.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                result_attributes,
+                vec![
+                    (String::from("name"), name.clone()),
+                    (String::from("symbol"), symbol.clone()),
+                ]
+            );
+
+            let result_remove = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.remove_attribute(id.clone(), String::from("name")));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("remove attribute failed")
+            }
+            .return_value();
+
+            assert_eq!(result_remove, Ok(()));
+
+            let result_keys = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.attribute_keys(id.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_keys, vec![String::from("symbol")]);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn frozen_attributes_cannot_be_updated(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let id = Id::U8(0);
+            let name = String::from("My AFT34");
+            let symbol = String::from("MFT34");
+
+            let constructor = ContractRef::new(id.clone(), name.clone(), symbol.clone());
+            let address = client
+                .instantiate("my_aft34_metadata", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let _freeze = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.freeze_metadata(id.clone()));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("freeze failed")
+            }
+            .return_value();
+
+            let result_set_attribute = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_attribute(
+                        id.clone(),
+                        String::from("name"),
+                        String::from("Swapped"),
+                    )
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_set_attribute, Err(AFT34Error::MetadataFrozen));
+
+            let result_name = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.get_attribute(id.clone(), String::from("name")));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_name, Some(name));
+
+            Ok(())
+        }
     }
 }
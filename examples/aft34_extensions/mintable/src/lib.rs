@@ -12,6 +12,15 @@ pub mod my_aft34_mintable {
         aft34: aft34::Data,
     }
 
+    #[overrider(aft34::Internal)]
+    fn _id_policy(&self) -> IdPolicy {
+        IdPolicy {
+            allowed_kinds: Some(&[IdKind::U8, IdKind::U16, IdKind::U32, IdKind::U64]),
+            canonical_integers: true,
+            max_bytes_len: None,
+        }
+    }
+
     impl Contract {
         /// The constructor
         #[ink(constructor)]
@@ -31,7 +40,7 @@ pub mod my_aft34_mintable {
         #[rustfmt::skip]
         use ink_e2e::build_message;
 
-        use test_helpers::{address_of, balance_of};
+        use test_helpers::{address_of, balance_of, owner_of};
 
         type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
 
@@ -120,5 +129,67 @@ pub mod my_aft34_mintable {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn mint_follows_id_policy(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft34_mintable", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let mint_non_canonical = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(alice), Id::U16(1)));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_non_canonical, Ok(()));
+            assert_eq!(
+                owner_of!(client, address, Id::U8(1)),
+                Some(address_of!(alice))
+            );
+
+            let mint_same_value = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(bob), Id::U8(1)));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(mint_same_value, Err(AFT34Error::TokenExists));
+
+            let mint_not_allowed = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(alice), Id::U128(1 << 64)));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                mint_not_allowed,
+                Err(AFT34Error::InvalidId(IdPolicyError::VariantNotAllowed))
+            );
+
+            let mint_canonical = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(alice), Id::U16(256)));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_canonical, Ok(()));
+            assert_eq!(balance_of!(client, address, alice), 2);
+
+            Ok(())
+        }
     }
 }
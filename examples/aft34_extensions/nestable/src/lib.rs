@@ -0,0 +1,164 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[allfeat_contracts::implementation(AFT34, AFT34Burnable, AFT34Mintable, AFT34Nestable)]
+#[allfeat_contracts::contract]
+pub mod my_aft34_nestable {
+    use openbrush::traits::Storage;
+
+    #[ink(event)]
+    pub struct ChildAdded {
+        #[ink(topic)]
+        parent_id: Id,
+        child: Child,
+        pending: bool,
+    }
+
+    #[ink(event)]
+    pub struct ChildAccepted {
+        #[ink(topic)]
+        parent_id: Id,
+        child: Child,
+    }
+
+    #[ink(event)]
+    pub struct ChildTransferred {
+        #[ink(topic)]
+        parent_id: Id,
+        child: Child,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft34: aft34::Data,
+        #[storage_field]
+        nestable: nestable::Data,
+    }
+
+    #[overrider(nestable::Internal)]
+    fn _emit_child_added_event(&self, parent_id: Id, child: Child, pending: bool) {
+        self.env().emit_event(ChildAdded {
+            parent_id,
+            child,
+            pending,
+        });
+    }
+
+    #[overrider(nestable::Internal)]
+    fn _emit_child_accepted_event(&self, parent_id: Id, child: Child) {
+        self.env().emit_event(ChildAccepted { parent_id, child });
+    }
+
+    #[overrider(nestable::Internal)]
+    fn _emit_child_transferred_event(&self, parent_id: Id, child: Child, to: AccountId) {
+        self.env().emit_event(ChildTransferred {
+            parent_id,
+            child,
+            to,
+        });
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use allfeat_contracts::aft34::AFT34Error::*;
+        use ink::env::test;
+        use openbrush::traits::String;
+
+        #[rustfmt::skip]
+        use super::*;
+
+        #[ink::test]
+        fn nesting_works() {
+            let accounts = test::default_accounts::<Environment>();
+            test::set_callee::<Environment>(accounts.django);
+            let mut aft34 = Contract::new();
+            let child = |id| Child::AFT34 {
+                contract: accounts.django,
+                id: Id::U8(id),
+            };
+            for id in 1..=3 {
+                assert!(AFT34MintableImpl::mint(&mut aft34, accounts.alice, Id::U8(id)).is_ok());
+            }
+            assert!(AFT34MintableImpl::mint(&mut aft34, accounts.bob, Id::U8(4)).is_ok());
+
+            assert!(AFT34NestableImpl::add_child(&mut aft34, Id::U8(1), child(2)).is_ok());
+            assert!(AFT34NestableImpl::add_child(&mut aft34, Id::U8(2), child(3)).is_ok());
+            assert_eq!(
+                AFT34NestableImpl::children(&aft34, Id::U8(1)),
+                vec![child(2)]
+            );
+            assert_eq!(
+                AFT34NestableImpl::parent_of(&aft34, Id::U8(3)),
+                Some(Id::U8(2))
+            );
+            assert_eq!(
+                AFT34Impl::owner_of(&aft34, Id::U8(3)),
+                Some(accounts.django)
+            );
+            assert_eq!(
+                AFT34NestableImpl::root_owner_of(&aft34, Id::U8(3)),
+                Some(accounts.alice)
+            );
+            assert_eq!(
+                AFT34NestableImpl::add_child(&mut aft34, Id::U8(3), child(1)),
+                Err(Custom(String::from("NestingCycle")))
+            );
+
+            test::set_caller::<Environment>(accounts.bob);
+            assert!(AFT34NestableImpl::add_child(&mut aft34, Id::U8(1), child(4)).is_ok());
+            assert_eq!(
+                AFT34NestableImpl::pending_children(&aft34, Id::U8(1)),
+                vec![child(4)]
+            );
+            assert_eq!(
+                AFT34NestableImpl::accept_child(&mut aft34, Id::U8(1), child(4)),
+                Err(NotApproved)
+            );
+
+            test::set_caller::<Environment>(accounts.alice);
+            assert!(AFT34NestableImpl::accept_child(&mut aft34, Id::U8(1), child(4)).is_ok());
+            assert_eq!(
+                AFT34NestableImpl::children(&aft34, Id::U8(1)),
+                vec![child(2), child(4)]
+            );
+
+            assert!(AFT34Impl::transfer(&mut aft34, accounts.charlie, Id::U8(1), vec![]).is_ok());
+            assert_eq!(
+                AFT34NestableImpl::root_owner_of(&aft34, Id::U8(3)),
+                Some(accounts.charlie)
+            );
+            assert_eq!(
+                AFT34NestableImpl::transfer_child(&mut aft34, Id::U8(2), child(3), accounts.alice),
+                Err(NotApproved)
+            );
+
+            test::set_caller::<Environment>(accounts.charlie);
+            assert!(AFT34NestableImpl::transfer_child(
+                &mut aft34,
+                Id::U8(2),
+                child(3),
+                accounts.charlie
+            )
+            .is_ok());
+            assert_eq!(
+                AFT34Impl::owner_of(&aft34, Id::U8(3)),
+                Some(accounts.charlie)
+            );
+            assert_eq!(AFT34NestableImpl::parent_of(&aft34, Id::U8(3)), None);
+            assert_eq!(
+                AFT34BurnableImpl::burn(&mut aft34, accounts.charlie, Id::U8(1)),
+                Err(Custom(String::from("ParentHasChildren")))
+            );
+        }
+    }
+}
@@ -0,0 +1,130 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[allfeat_contracts::implementation(AFT34, AFT34Metadata, AFT34OnchainMetadata, AFT34Mintable)]
+#[allfeat_contracts::contract]
+pub mod my_aft34_onchain_metadata {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft34: aft34::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn set_attributes(
+            &mut self,
+            id: Id,
+            attributes: Vec<(String, String)>,
+        ) -> Result<(), AFT34Error> {
+            metadata::Internal::_set_attributes(self, id, attributes)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        use allfeat_contracts::aft34::extensions::{
+            mintable::aft34mintable_external::AFT34Mintable,
+            onchain_metadata::aft34onchainmetadata_external::AFT34OnchainMetadata,
+        };
+
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::build_message;
+
+        use test_helpers::address_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn onchain_token_uri_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate(
+                    "my_aft34_onchain_metadata",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let id = Id::U8(1);
+
+            let result_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_token_uri, Err(AFT34Error::TokenNotExists));
+
+            let mint_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(alice), id.clone()));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+
+            let set_attributes_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_attributes(
+                        id.clone(),
+                        vec![
+                            (String::from("producer"), String::from("Alice")),
+                            (String::from("name"), String::from("Track #1")),
+                            (
+                                String::from("description"),
+                                String::from("Recorded \"live\"\nin Paris"),
+                            ),
+                            (String::from("image"), String::from("ipfs://cid")),
+                        ],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set attributes failed")
+            }
+            .return_value();
+
+            assert_eq!(set_attributes_tx, Ok(()));
+
+            let result_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            // {"name":"Track #1","description":"Recorded \"live\"\nin Paris","image":"ipfs://cid",
+            // "attributes":[{"trait_type":"producer","value":"Alice"}]}
+            assert_eq!(
+                result_token_uri,
+                Ok(String::from(
+                    "data:application/json;base64,eyJuYW1lIjoiVHJhY2sgIzEiLCJkZXNjcmlwdGlvbiI6IlJlY29yZGVkIFwibGl2ZVwiXG5pbiBQYXJpcyIsImltYWdlIjoiaXBmczovL2NpZCIsImF0dHJpYnV0ZXMiOlt7InRyYWl0X3R5cGUiOiJwcm9kdWNlciIsInZhbHVlIjoiQWxpY2UifV19"
+                ))
+            );
+
+            Ok(())
+        }
+    }
+}
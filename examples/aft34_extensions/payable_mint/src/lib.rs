@@ -41,19 +41,16 @@ pub mod my_aft34_payable_mint {
                 collection_id.clone(),
                 String::from("name"),
                 name,
-            );
+            )
+            .expect("Should set name");
             metadata::InternalImpl::_set_attribute(
                 &mut instance,
-                collection_id.clone(),
+                collection_id,
                 String::from("symbol"),
                 symbol,
-            );
-            metadata::InternalImpl::_set_attribute(
-                &mut instance,
-                collection_id,
-                String::from("baseUri"),
-                base_uri,
-            );
+            )
+            .expect("Should set symbol");
+            payable_mint::Internal::_set_base_uri(&mut instance, Some(base_uri));
             instance.payable_mint.max_supply = max_supply;
             instance.payable_mint.price_per_mint = price_per_mint;
             instance.payable_mint.last_token_id = 0;
@@ -67,7 +64,7 @@ pub mod my_aft34_payable_mint {
             aft34_external::AFT34,
             extensions::payable_mint::aft34payablemint_external::AFT34PayableMint, AFT34Error::*,
         };
-        use ink::env::test;
+        use ink::env::{hash::Keccak256, test};
 
         #[rustfmt::skip]
         use super::*;
@@ -134,6 +131,283 @@ pub mod my_aft34_payable_mint {
             );
         }
 
+        #[ink::test]
+        fn token_uri_works() {
+            let mut aft34 = Contract::new(
+                String::from("Allfeat34"),
+                String::from("AFT34"),
+                String::from(BASE_URI),
+                10,
+                PRICE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+            set_sender(accounts.bob);
+
+            assert_eq!(
+                AFT34PayableMintImpl::token_uri(&aft34, Id::U64(1)),
+                Err(TokenNotExists)
+            );
+
+            test::set_value_transferred::<ink::env::DefaultEnvironment>(PRICE * 2);
+            assert!(AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 2).is_ok());
+
+            assert_eq!(
+                AFT34PayableMintImpl::token_uri(&aft34, Id::U64(1)),
+                Ok(Some(String::from("ipfs://myIpfsUri/1.json")))
+            );
+            assert_eq!(
+                AFT34PayableMintImpl::token_uri(&aft34, Id::U64(2)),
+                Ok(Some(String::from("ipfs://myIpfsUri/2.json")))
+            );
+
+            assert_eq!(
+                AFT34PayableMintImpl::set_uri_suffix(&mut aft34, None),
+                Err(Custom(String::from("O::CallerIsNotOwner")))
+            );
+            set_sender(accounts.alice);
+            assert!(AFT34PayableMintImpl::set_uri_suffix(&mut aft34, Some(String::new())).is_ok());
+            assert_eq!(
+                AFT34PayableMintImpl::token_uri(&aft34, Id::U64(1)),
+                Ok(Some(String::from("ipfs://myIpfsUri/1")))
+            );
+        }
+
+        #[ink::test]
+        fn sale_phases_work() {
+            let mut aft34 = Contract::new(
+                String::from("Allfeat34"),
+                String::from("AFT34"),
+                String::from(BASE_URI),
+                10,
+                PRICE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+
+            let (bob_leaf, charlie_leaf) = (leaf(&accounts.bob), leaf(&accounts.charlie));
+            let presale = SalePhase {
+                price: PRICE / 2,
+                start: 100,
+                end: 200,
+                max_per_wallet: None,
+                supply: Some(3),
+                merkle_root: Some(node(&bob_leaf, &charlie_leaf)),
+            };
+            let public_sale = SalePhase {
+                price: PRICE,
+                start: 200,
+                end: u64::MAX,
+                max_per_wallet: Some(2),
+                supply: None,
+                merkle_root: None,
+            };
+
+            set_sender(accounts.alice);
+            assert!(AFT34PayableMintImpl::set_phase(&mut aft34, 0, presale.clone()).is_ok());
+            assert!(AFT34PayableMintImpl::set_phase(&mut aft34, 1, public_sale.clone()).is_ok());
+            assert_eq!(AFT34PayableMintImpl::phases_count(&aft34), 2);
+
+            // Before the presale
+            test::set_block_timestamp::<Environment>(50);
+            set_sender(accounts.bob);
+            test::set_value_transferred::<Environment>(PRICE / 2);
+            assert_eq!(AFT34PayableMintImpl::active_phase(&aft34), None);
+            assert_eq!(
+                AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 1),
+                Err(Custom(String::from("NoActivePhase")))
+            );
+
+            // During the presale
+            test::set_block_timestamp::<Environment>(150);
+            assert_eq!(
+                AFT34PayableMintImpl::active_phase(&aft34),
+                Some((0, presale))
+            );
+            assert_eq!(AFT34PayableMintImpl::price(&aft34), PRICE / 2);
+            assert!(AFT34PayableMintImpl::mint_allowlisted(
+                &mut aft34,
+                accounts.bob,
+                1,
+                vec![charlie_leaf]
+            )
+            .is_ok());
+            assert_eq!(
+                AFT34PayableMintImpl::remaining_allocation(&aft34, accounts.bob),
+                2
+            );
+
+            set_sender(accounts.django);
+            assert_eq!(
+                AFT34PayableMintImpl::mint_allowlisted(
+                    &mut aft34,
+                    accounts.django,
+                    1,
+                    vec![charlie_leaf]
+                ),
+                Err(Custom(String::from("NotAllowlisted")))
+            );
+
+            // During the public sale
+            test::set_block_timestamp::<Environment>(250);
+            set_sender(accounts.bob);
+            test::set_value_transferred::<Environment>(PRICE * 2);
+            assert!(AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 2).is_ok());
+            assert_eq!(
+                AFT34PayableMintImpl::remaining_allocation(&aft34, accounts.bob),
+                0
+            );
+
+            test::set_value_transferred::<Environment>(PRICE);
+            assert_eq!(
+                AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 1),
+                Err(Custom(String::from("WalletLimitReached")))
+            );
+            assert_eq!(AFT34Impl::balance_of(&aft34, accounts.bob), 3);
+        }
+
+        #[ink::test]
+        fn payment_token_settings_work() {
+            let mut aft34 = Contract::new(
+                String::from("Allfeat34"),
+                String::from("AFT34"),
+                String::from(BASE_URI),
+                10,
+                PRICE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+
+            set_sender(accounts.bob);
+            assert!(
+                AFT34PayableMintImpl::set_payment_token(&mut aft34, Some(accounts.frank)).is_err()
+            );
+
+            set_sender(accounts.alice);
+            assert!(
+                AFT34PayableMintImpl::set_payment_token(&mut aft34, Some(accounts.frank)).is_ok()
+            );
+            assert!(
+                AFT34PayableMintImpl::set_payout_address(&mut aft34, Some(accounts.eve)).is_ok()
+            );
+            assert_eq!(
+                AFT34PayableMintImpl::payment_token(&aft34),
+                Some(accounts.frank)
+            );
+            assert_eq!(
+                AFT34PayableMintImpl::payout_address(&aft34),
+                Some(accounts.eve)
+            );
+
+            // Mints are paid in the payment token, the native balance is refused
+            set_sender(accounts.bob);
+            test::set_value_transferred::<Environment>(PRICE);
+            assert_eq!(
+                AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 1),
+                Err(Custom(String::from("BadMintValue")))
+            );
+            assert_eq!(AFT34Impl::total_supply(&aft34), 0);
+        }
+
+        #[ink::test]
+        fn overpayment_is_refunded() {
+            let mut aft34 = Contract::new(
+                String::from("Allfeat34"),
+                String::from("AFT34"),
+                String::from(BASE_URI),
+                10,
+                PRICE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+            test::set_account_balance::<Environment>(test::callee::<Environment>(), PRICE * 10);
+            set_sender(accounts.bob);
+
+            let bob_balance = test::get_account_balance::<Environment>(accounts.bob).unwrap();
+            test::set_value_transferred::<Environment>(PRICE * 3);
+            assert!(AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 2).is_ok());
+            assert_eq!(AFT34Impl::balance_of(&aft34, accounts.bob), 2);
+            assert_eq!(
+                test::get_account_balance::<Environment>(accounts.bob),
+                Ok(bob_balance + PRICE)
+            );
+
+            test::set_value_transferred::<Environment>(PRICE);
+            assert_eq!(
+                AFT34PayableMintImpl::mint(&mut aft34, accounts.bob, 2),
+                Err(Custom(String::from("BadMintValue")))
+            );
+        }
+
+        #[ink::test]
+        fn withdraw_splits_work() {
+            let mut aft34 = Contract::new(
+                String::from("Allfeat34"),
+                String::from("AFT34"),
+                String::from(BASE_URI),
+                10,
+                PRICE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+            set_sender(accounts.alice);
+
+            assert_eq!(
+                AFT34PayableMintImpl::set_payout_splits(
+                    &mut aft34,
+                    vec![(accounts.charlie, 1), (accounts.django, 0)]
+                ),
+                Err(Custom(String::from("InvalidPayoutSplits")))
+            );
+            assert_eq!(
+                AFT34PayableMintImpl::set_payout_splits(
+                    &mut aft34,
+                    vec![(accounts.charlie, 1); 11]
+                ),
+                Err(Custom(String::from("TooManyPayoutSplits")))
+            );
+            let splits = vec![(accounts.charlie, 1), (accounts.django, 3)];
+            assert!(AFT34PayableMintImpl::set_payout_splits(&mut aft34, splits.clone()).is_ok());
+            assert_eq!(AFT34PayableMintImpl::payout_splits(&aft34), splits);
+
+            let minimum_balance = ink::env::minimum_balance::<Environment>();
+            test::set_account_balance::<Environment>(
+                test::callee::<Environment>(),
+                minimum_balance + 4_000,
+            );
+            let charlie_balance =
+                test::get_account_balance::<Environment>(accounts.charlie).unwrap();
+            let django_balance = test::get_account_balance::<Environment>(accounts.django).unwrap();
+
+            set_sender(accounts.bob);
+            assert_eq!(
+                AFT34PayableMintImpl::withdraw(&mut aft34),
+                Err(Custom(String::from("O::CallerIsNotOwner")))
+            );
+
+            set_sender(accounts.alice);
+            assert!(AFT34PayableMintImpl::withdraw(&mut aft34).is_ok());
+            assert_eq!(
+                test::get_account_balance::<Environment>(accounts.charlie),
+                Ok(charlie_balance + 1_000)
+            );
+            assert_eq!(
+                test::get_account_balance::<Environment>(accounts.django),
+                Ok(django_balance + 3_000)
+            );
+        }
+
+        fn leaf(account: &AccountId) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<Keccak256>(account.as_ref(), &mut output);
+            output
+        }
+
+        fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+            let (left, right) = if a <= b { (a, b) } else { (b, a) };
+            let mut pair = [0u8; 64];
+            pair[..32].copy_from_slice(left);
+            pair[32..].copy_from_slice(right);
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<Keccak256>(&pair, &mut output);
+            output
+        }
+
         fn set_sender(sender: AccountId) {
             ink::env::test::set_caller::<Environment>(sender);
         }
@@ -0,0 +1,145 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[allfeat_contracts::implementation(AFT34, AFT34Mintable, AFT34Rentable)]
+#[allfeat_contracts::contract]
+pub mod my_aft34_rentable {
+    use openbrush::traits::Storage;
+
+    #[ink(event)]
+    pub struct UpdateUser {
+        #[ink(topic)]
+        id: Id,
+        #[ink(topic)]
+        user: Option<AccountId>,
+        expires: Timestamp,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft34: aft34::Data,
+        #[storage_field]
+        rentable: rentable::Data,
+    }
+
+    #[overrider(rentable::Internal)]
+    fn _emit_update_user_event(&self, id: Id, user: Option<AccountId>, expires: Timestamp) {
+        self.env().emit_event(UpdateUser { id, user, expires });
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use allfeat_contracts::aft34::AFT34Error::*;
+        use ink::env::test;
+        use openbrush::traits::String;
+
+        #[rustfmt::skip]
+        use super::*;
+
+        #[ink::test]
+        fn rental_works() {
+            let mut aft34 = Contract::new();
+            let accounts = test::default_accounts::<Environment>();
+            assert!(AFT34MintableImpl::mint(&mut aft34, accounts.alice, Id::U8(1)).is_ok());
+
+            test::set_caller::<Environment>(accounts.bob);
+            assert_eq!(
+                AFT34RentableImpl::set_user(&mut aft34, Id::U8(1), Some(accounts.bob), 1_000),
+                Err(NotApproved)
+            );
+
+            test::set_caller::<Environment>(accounts.alice);
+            assert!(
+                AFT34RentableImpl::set_user(&mut aft34, Id::U8(1), Some(accounts.bob), 1_000)
+                    .is_ok()
+            );
+            assert_eq!(
+                AFT34RentableImpl::user_of(&aft34, Id::U8(1)),
+                Some(accounts.bob)
+            );
+            assert_eq!(
+                AFT34RentableImpl::user_expires(&aft34, Id::U8(1)),
+                Some(1_000)
+            );
+            assert_eq!(AFT34Impl::owner_of(&aft34, Id::U8(1)), Some(accounts.alice));
+
+            test::set_block_timestamp::<Environment>(1_000);
+            assert_eq!(AFT34RentableImpl::user_of(&aft34, Id::U8(1)), None);
+            assert_eq!(AFT34RentableImpl::user_expires(&aft34, Id::U8(1)), None);
+
+            assert!(
+                AFT34RentableImpl::set_user(&mut aft34, Id::U8(1), Some(accounts.bob), 2_000)
+                    .is_ok()
+            );
+            assert!(AFT34Impl::transfer(&mut aft34, accounts.charlie, Id::U8(1), vec![]).is_ok());
+            assert_eq!(AFT34RentableImpl::user_of(&aft34, Id::U8(1)), None);
+            assert_eq!(AFT34RentableImpl::user_expires(&aft34, Id::U8(1)), None);
+            assert_eq!(test::recorded_events().count(), 3);
+        }
+
+        #[ink::test]
+        fn expiring_approval_lapses() {
+            let mut aft34 = Contract::new();
+            let accounts = test::default_accounts::<Environment>();
+            assert!(AFT34MintableImpl::mint(&mut aft34, accounts.alice, Id::U8(1)).is_ok());
+
+            assert_eq!(
+                AFT34Impl::approve_until(&mut aft34, accounts.bob, Some(Id::U8(1)), 0),
+                Err(Custom(String::from("InvalidExpiry")))
+            );
+            assert!(
+                AFT34Impl::approve_until(&mut aft34, accounts.bob, Some(Id::U8(1)), 1_000).is_ok()
+            );
+            assert!(AFT34Impl::approve_until(&mut aft34, accounts.charlie, None, 2_000).is_ok());
+            assert_eq!(
+                AFT34Impl::get_approved(&aft34, Id::U8(1)),
+                Some(accounts.bob)
+            );
+            assert_eq!(
+                AFT34Impl::approval_expiry(&aft34, accounts.alice, accounts.bob, Some(Id::U8(1))),
+                Some(Some(1_000))
+            );
+
+            test::set_block_timestamp::<Environment>(1_000);
+            assert_eq!(AFT34Impl::get_approved(&aft34, Id::U8(1)), None);
+            assert!(!AFT34Impl::allowance(
+                &aft34,
+                accounts.alice,
+                accounts.bob,
+                Some(Id::U8(1))
+            ));
+            assert!(AFT34Impl::allowance(
+                &aft34,
+                accounts.alice,
+                accounts.charlie,
+                None
+            ));
+
+            test::set_block_timestamp::<Environment>(2_000);
+            assert!(!AFT34Impl::allowance(
+                &aft34,
+                accounts.alice,
+                accounts.charlie,
+                None
+            ));
+            assert_eq!(
+                AFT34Impl::approval_expiry(&aft34, accounts.alice, accounts.charlie, None),
+                None
+            );
+
+            assert!(AFT34Impl::approve(&mut aft34, accounts.django, None, true).is_ok());
+            assert_eq!(
+                AFT34Impl::approval_expiry(&aft34, accounts.alice, accounts.django, None),
+                Some(None)
+            );
+        }
+    }
+}
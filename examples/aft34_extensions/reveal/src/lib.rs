@@ -0,0 +1,162 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[allfeat_contracts::implementation(AFT34, AFT34Reveal)]
+#[allfeat_contracts::contract]
+pub mod my_aft34_reveal {
+    use openbrush::{modifiers, traits::Storage};
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft34: aft34::Data,
+        #[storage_field]
+        reveal: reveal::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(placeholder_uri: String, base_uri: String, collection_size: u64) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::InternalImpl::_init_with_owner(&mut instance, caller);
+            reveal::Internal::_set_placeholder_uri(&mut instance, Some(placeholder_uri));
+            reveal::Internal::_set_base_uri(&mut instance, Some(base_uri));
+            reveal::Internal::_set_collection_size(&mut instance, collection_size)
+                .expect("Should set collection size");
+            instance
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn mint(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error> {
+            aft34::Internal::_mint_to(self, to, id)?;
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use allfeat_contracts::aft34::AFT34Error::*;
+        use ink::env::{hash::Keccak256, test};
+
+        #[rustfmt::skip]
+        use super::*;
+
+        const PLACEHOLDER_URI: &str = "ipfs://placeholder.json";
+        const BASE_URI: &str = "ipfs://myIpfsUri/";
+        const COLLECTION_SIZE: u64 = 5;
+        const SEED: [u8; 32] = [7; 32];
+
+        fn seed_commitment(seed: &[u8; 32]) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<Keccak256>(seed, &mut output);
+            output
+        }
+
+        #[ink::test]
+        fn reveal_works() {
+            let mut aft34 = Contract::new(
+                String::from(PLACEHOLDER_URI),
+                String::from(BASE_URI),
+                COLLECTION_SIZE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+
+            assert_eq!(
+                AFT34RevealImpl::reveal(&mut aft34, SEED),
+                Err(Custom(String::from("ProvenanceNotSet")))
+            );
+
+            assert!(
+                AFT34RevealImpl::set_provenance(&mut aft34, [1; 32], seed_commitment(&SEED))
+                    .is_ok()
+            );
+            assert_eq!(AFT34RevealImpl::provenance(&aft34), Some([1; 32]));
+            assert_eq!(
+                AFT34RevealImpl::seed_commitment(&aft34),
+                Some(seed_commitment(&SEED))
+            );
+            assert_eq!(
+                AFT34RevealImpl::set_provenance(&mut aft34, [2; 32], seed_commitment(&SEED)),
+                Err(Custom(String::from("ProvenanceAlreadySet")))
+            );
+
+            for id in 1..=COLLECTION_SIZE {
+                assert!(aft34.mint(accounts.bob, Id::U64(id)).is_ok());
+            }
+            assert_eq!(
+                reveal::Internal::_set_collection_size(&mut aft34, COLLECTION_SIZE + 1),
+                Err(Custom(String::from("AlreadyMinted")))
+            );
+
+            assert!(!AFT34RevealImpl::is_revealed(&aft34));
+            assert_eq!(
+                AFT34RevealImpl::token_uri(&aft34, Id::U64(1)),
+                Ok(Some(String::from(PLACEHOLDER_URI)))
+            );
+            assert_eq!(AFT34RevealImpl::metadata_id(&aft34, Id::U64(1)), Ok(None));
+            assert_eq!(
+                AFT34RevealImpl::reveal(&mut aft34, [8; 32]),
+                Err(Custom(String::from("InvalidSeed")))
+            );
+
+            test::set_caller::<Environment>(accounts.bob);
+            assert!(AFT34RevealImpl::reveal(&mut aft34, SEED).is_err());
+
+            test::set_caller::<Environment>(accounts.alice);
+            assert!(AFT34RevealImpl::reveal(&mut aft34, SEED).is_ok());
+            assert!(AFT34RevealImpl::is_revealed(&aft34));
+            assert_eq!(
+                AFT34RevealImpl::reveal(&mut aft34, SEED),
+                Err(Custom(String::from("AlreadyRevealed")))
+            );
+
+            let starting_offset = AFT34RevealImpl::starting_offset(&aft34).unwrap();
+            assert!(starting_offset < COLLECTION_SIZE);
+
+            let mut metadata_ids = Vec::new();
+            for id in 1..=COLLECTION_SIZE {
+                let metadata_id = AFT34RevealImpl::metadata_id(&aft34, Id::U64(id))
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(
+                    metadata_id,
+                    (id - 1 + starting_offset) % COLLECTION_SIZE + 1
+                );
+                assert_eq!(
+                    AFT34RevealImpl::token_uri(&aft34, Id::U64(id)),
+                    Ok(Some(format!("{}{}", BASE_URI, metadata_id)))
+                );
+                metadata_ids.push(metadata_id);
+            }
+            metadata_ids.sort();
+            assert_eq!(metadata_ids, (1..=COLLECTION_SIZE).collect::<Vec<_>>());
+
+            assert_eq!(
+                AFT34RevealImpl::token_uri(&aft34, Id::U64(COLLECTION_SIZE + 1)),
+                Err(TokenNotExists)
+            );
+        }
+
+        #[ink::test]
+        fn provenance_cannot_be_set_after_mint() {
+            let mut aft34 = Contract::new(
+                String::from(PLACEHOLDER_URI),
+                String::from(BASE_URI),
+                COLLECTION_SIZE,
+            );
+            let accounts = test::default_accounts::<Environment>();
+            assert!(aft34.mint(accounts.bob, Id::U64(1)).is_ok());
+
+            assert_eq!(
+                AFT34RevealImpl::set_provenance(&mut aft34, [1; 32], seed_commitment(&SEED)),
+                Err(Custom(String::from("AlreadyMinted")))
+            );
+            assert_eq!(AFT34RevealImpl::provenance(&aft34), None);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[allfeat_contracts::implementation(AFT34, AFT34Burnable, AFT34Soulbound)]
+#[allfeat_contracts::contract]
+pub mod my_aft34_soulbound {
+    use openbrush::{modifiers, traits::Storage};
+
+    #[ink(event)]
+    pub struct Locked {
+        #[ink(topic)]
+        id: Id,
+    }
+
+    #[ink(event)]
+    pub struct Unlocked {
+        #[ink(topic)]
+        id: Id,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft34: aft34::Data,
+        #[storage_field]
+        soulbound: soulbound::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    #[overrider(soulbound::Internal)]
+    fn _emit_locked_event(&self, id: Id) {
+        self.env().emit_event(Locked { id });
+    }
+
+    #[overrider(soulbound::Internal)]
+    fn _emit_unlocked_event(&self, id: Id) {
+        self.env().emit_event(Unlocked { id });
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::InternalImpl::_init_with_owner(&mut instance, caller);
+            soulbound::Internal::_set_burn_allowed(&mut instance, true);
+            instance
+        }
+
+        /// Issues the badge `id` to `to`
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn issue(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error> {
+            aft34::Internal::_mint_to(self, to, id)?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn unlock(&mut self, id: Id) -> Result<(), AFT34Error> {
+            soulbound::Internal::_unlock(self, id)
+        }
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use allfeat_contracts::aft34::AFT34Error::*;
+        use ink::env::test;
+
+        #[rustfmt::skip]
+        use super::*;
+
+        #[ink::test]
+        fn soulbound_tokens_cannot_be_transferred() {
+            let mut aft34 = Contract::new();
+            let accounts = test::default_accounts::<Environment>();
+            assert!(aft34.issue(accounts.bob, Id::U8(1)).is_ok());
+            assert!(aft34.issue(accounts.bob, Id::U8(2)).is_ok());
+            assert_eq!(test::recorded_events().count(), 2);
+
+            assert_eq!(AFT34SoulboundImpl::locked(&aft34, Id::U8(1)), Ok(true));
+            assert_eq!(
+                AFT34SoulboundImpl::locked(&aft34, Id::U8(3)),
+                Err(TokenNotExists)
+            );
+
+            test::set_caller::<Environment>(accounts.bob);
+            assert_eq!(
+                AFT34Impl::transfer(&mut aft34, accounts.charlie, Id::U8(1), vec![]),
+                Err(Custom(String::from("TokenIsSoulbound")))
+            );
+            assert_eq!(AFT34Impl::owner_of(&aft34, Id::U8(1)), Some(accounts.bob));
+
+            test::set_caller::<Environment>(accounts.alice);
+            assert!(aft34.unlock(Id::U8(1)).is_ok());
+            assert_eq!(AFT34SoulboundImpl::locked(&aft34, Id::U8(1)), Ok(false));
+
+            test::set_caller::<Environment>(accounts.bob);
+            assert!(AFT34Impl::transfer(&mut aft34, accounts.charlie, Id::U8(1), vec![]).is_ok());
+            assert_eq!(
+                AFT34Impl::owner_of(&aft34, Id::U8(1)),
+                Some(accounts.charlie)
+            );
+
+            assert!(AFT34BurnableImpl::burn(&mut aft34, accounts.bob, Id::U8(2)).is_ok());
+            assert_eq!(AFT34Impl::balance_of(&aft34, accounts.bob), 0);
+        }
+    }
+}
@@ -19,7 +19,8 @@ pub mod my_aft34_uri_storage {
         #[ink(constructor)]
         pub fn new(base_uri: Option<URI>) -> Self {
             let mut instance = Default::default();
-            uri_storage::Internal::_set_base_uri(&mut instance, base_uri);
+            uri_storage::Internal::_set_base_uri(&mut instance, base_uri)
+                .expect("Should set base uri");
             instance
         }
         #[ink(message)]
@@ -27,6 +28,39 @@ pub mod my_aft34_uri_storage {
             uri_storage::Internal::_set_token_uri(self, token_id, token_uri)?;
             Ok(())
         }
+
+        #[ink(message)]
+        pub fn set_token_uri_batch(
+            &mut self,
+            token_uris: Vec<(Id, URI)>,
+        ) -> Result<(), AFT34Error> {
+            uri_storage::Internal::_set_token_uri_batch(self, token_uris)
+        }
+
+        #[ink(message)]
+        pub fn set_uri_fallback(
+            &mut self,
+            enabled: bool,
+            uri_suffix: Option<URI>,
+        ) -> Result<(), AFT34Error> {
+            uri_storage::Internal::_set_uri_fallback(self, enabled)?;
+            uri_storage::Internal::_set_uri_suffix(self, uri_suffix)
+        }
+
+        #[ink(message)]
+        pub fn set_base_uri(&mut self, base_uri: Option<URI>) -> Result<(), AFT34Error> {
+            uri_storage::Internal::_set_base_uri(self, base_uri)
+        }
+
+        #[ink(message)]
+        pub fn freeze_metadata(&mut self, token_id: Id) -> Result<(), AFT34Error> {
+            uri_storage::Internal::_freeze_metadata(self, token_id)
+        }
+
+        #[ink(message)]
+        pub fn freeze_all(&mut self) {
+            uri_storage::Internal::_freeze_all(self)
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -202,5 +236,297 @@ pub mod my_aft34_uri_storage {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn base_uri_fallback_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let id_1 = Id::U8(1);
+            let id_2 = Id::Bytes(vec![0xab, 0x01]);
+            let base = Some(URI::from("https://allfeat.network/"));
+
+            let constructor = ContractRef::new(base.clone());
+            let address = client
+                .instantiate(
+                    "my_aft34_uri_storage",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            for id in [id_1.clone(), id_2.clone()] {
+                let _mint = {
+                    let _msg = build_message::<ContractRef>(address.clone())
+                        .call(|contract| contract.mint(address_of!(alice), id.clone()));
+                    client
+                        .call(&ink_e2e::alice(), _msg, 0, None)
+                        .await
+                        .expect("mint failed")
+                }
+                .return_value();
+            }
+
+            let result_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_1.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            // fallback is disabled by default
+            assert_eq!(result_token_uri, Ok(None));
+
+            let _set_fallback = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_uri_fallback(true, Some(URI::from(".json"))));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set fallback failed")
+            }
+            .return_value();
+
+            let result_token_uri_1 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_1.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let result_token_uri_2 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_2.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                result_token_uri_1,
+                Ok(Some(URI::from("https://allfeat.network/1.json")))
+            );
+            assert_eq!(
+                result_token_uri_2,
+                Ok(Some(URI::from("https://allfeat.network/0xab01.json")))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn set_token_uri_batch_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let id_1 = Id::U8(1);
+            let id_2 = Id::U8(2);
+            let id_3 = Id::U8(3);
+
+            let constructor = ContractRef::new(None);
+            let address = client
+                .instantiate(
+                    "my_aft34_uri_storage",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            for id in [id_1.clone(), id_2.clone()] {
+                let _mint = {
+                    let _msg = build_message::<ContractRef>(address.clone())
+                        .call(|contract| contract.mint(address_of!(alice), id.clone()));
+                    client
+                        .call(&ink_e2e::alice(), _msg, 0, None)
+                        .await
+                        .expect("mint failed")
+                }
+                .return_value();
+            }
+
+            let result_set_batch = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_token_uri_batch(vec![
+                        (id_1.clone(), URI::from("aft34_1")),
+                        (id_3.clone(), URI::from("aft34_3")),
+                    ])
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            // id_3 isn't minted
+            assert_eq!(result_set_batch, Err(AFT34Error::TokenNotExists));
+
+            let result_set_batch = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_token_uri_batch(vec![
+                        (id_1.clone(), URI::from("aft34_1")),
+                        (id_2.clone(), URI::from("aft34_2")),
+                    ])
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set batch failed")
+            }
+            .return_value();
+
+            assert_eq!(result_set_batch, Ok(()));
+
+            let result_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_2.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_token_uri, Ok(Some(URI::from("aft34_2"))));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn frozen_metadata_cannot_be_updated(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let id_1 = Id::U8(1);
+            let id_2 = Id::U8(2);
+
+            let constructor = ContractRef::new(None);
+            let address = client
+                .instantiate(
+                    "my_aft34_uri_storage",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            for id in [id_1.clone(), id_2.clone()] {
+                let _mint = {
+                    let _msg = build_message::<ContractRef>(address.clone())
+                        .call(|contract| contract.mint(address_of!(alice), id.clone()));
+                    client
+                        .call(&ink_e2e::alice(), _msg, 0, None)
+                        .await
+                        .expect("mint failed")
+                }
+                .return_value();
+            }
+
+            let _set_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_token_uri(id_1.clone(), URI::from("aft34_1")));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set token uri failed")
+            }
+            .return_value();
+
+            let result_freeze = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.freeze_metadata(id_1.clone()));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("freeze failed")
+            }
+            .return_value();
+
+            assert_eq!(result_freeze, Ok(()));
+
+            let result_freeze_missing = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.freeze_metadata(Id::U8(3)));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_freeze_missing, Err(AFT34Error::TokenNotExists));
+
+            let is_frozen = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.is_metadata_frozen(id_1.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert!(is_frozen);
+
+            let result_set_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_token_uri(id_1.clone(), URI::from("swapped")));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_set_token_uri, Err(AFT34Error::MetadataFrozen));
+
+            let result_set_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_token_uri(id_2.clone(), URI::from("aft34_2")));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_set_token_uri, Ok(()));
+
+            let _set_base_uri = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_base_uri(Some(URI::from("https://allfeat.network/")))
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set base uri failed")
+            }
+            .return_value();
+
+            let result_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_1.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_token_uri, Ok(Some(URI::from("aft34_1"))));
+
+            let _freeze_all = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.freeze_all());
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("freeze all failed")
+            }
+            .return_value();
+
+            let result_set_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_token_uri(id_2.clone(), URI::from("aft34_2")));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_set_token_uri, Err(AFT34Error::MetadataFrozen));
+
+            let result_set_base_uri = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_base_uri(Some(URI::from("https://allfeat.network/")))
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(result_set_base_uri, Err(AFT34Error::MetadataFrozen));
+
+            Ok(())
+        }
     }
 }
@@ -237,6 +237,70 @@ pub mod my_aft37 {
             Ok(())
         }
 
+        #[ink_e2e::test]
+        async fn expiring_allowance_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token = Id::U8(0);
+
+            let expired_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.approve_until(address_of!(bob), Some(token.clone()), 10, 1)
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                expired_tx,
+                Err(AFT37Error::Custom(String::from("InvalidExpiry")))
+            );
+
+            let approve_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.approve_until(address_of!(bob), Some(token.clone()), 10, u64::MAX)
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("approve_until failed")
+            }
+            .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let allowance = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.allowance(address_of!(alice), address_of!(bob), Some(token.clone()))
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(allowance, 10);
+
+            let expiry = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.approval_expiry(
+                        address_of!(alice),
+                        address_of!(bob),
+                        Some(token.clone()),
+                    )
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(expiry, Some(Some(u64::MAX)));
+
+            Ok(())
+        }
+
         #[ink_e2e::test]
         async fn transfer_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             let constructor = ContractRef::new();
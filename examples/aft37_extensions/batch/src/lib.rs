@@ -39,6 +39,7 @@ pub mod my_aft37_batch {
         #[rustfmt::skip]
         use ink_e2e::build_message;
 
+        use openbrush::traits::String;
         use test_helpers::{address_of, balance_of_37};
 
         type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
@@ -356,5 +357,273 @@ pub mod my_aft37_batch {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_multi_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_batch", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token_1 = Id::U8(0);
+            let token_2 = Id::U8(1);
+
+            let mint_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.mint(
+                        address_of!(alice),
+                        vec![(token_1.clone(), 10), (token_2.clone(), 20)],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+
+            let batch_transfer_multi_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.batch_transfer_multi(
+                        vec![
+                            (address_of!(bob), token_1.clone(), 3),
+                            (address_of!(charlie), token_1.clone(), 4),
+                            (address_of!(bob), token_2.clone(), 5),
+                        ],
+                        vec![],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("batch transfer multi failed")
+            }
+            .return_value();
+
+            assert_eq!(batch_transfer_multi_tx, Ok(()));
+
+            assert_eq!(
+                balance_of_37!(client, address, bob, Some(token_1.clone())),
+                3
+            );
+            assert_eq!(
+                balance_of_37!(client, address, bob, Some(token_2.clone())),
+                5
+            );
+            assert_eq!(
+                balance_of_37!(client, address, charlie, Some(token_1.clone())),
+                4
+            );
+            assert_eq!(
+                balance_of_37!(client, address, alice, Some(token_1.clone())),
+                3
+            );
+            assert_eq!(
+                balance_of_37!(client, address, alice, Some(token_2.clone())),
+                15
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_multi_from_over_allowance_should_fail(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_batch", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token = Id::U8(0);
+
+            let mint_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(alice), vec![(token.clone(), 10)]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+
+            let approve_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.approve(address_of!(bob), Some(token.clone()), 5));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("approve failed")
+            }
+            .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let batch_transfer_multi_from_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.batch_transfer_multi_from(
+                        address_of!(alice),
+                        vec![
+                            (address_of!(charlie), token.clone(), 3),
+                            (address_of!(dave), token.clone(), 3),
+                        ],
+                        vec![],
+                    )
+                });
+                client.call_dry_run(&ink_e2e::bob(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert!(matches!(batch_transfer_multi_from_tx, Err(_)));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn balance_of_batch_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_batch", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token_1 = Id::U8(0);
+            let token_2 = Id::U8(1);
+
+            let amount_1 = 1;
+            let amount_2 = 20;
+
+            let mint_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.mint(
+                        address_of!(alice),
+                        vec![
+                            (token_1.clone(), amount_1.clone()),
+                            (token_2.clone(), amount_2.clone()),
+                        ],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+
+            let balances = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.balance_of_batch(vec![
+                        (address_of!(alice), Some(token_1.clone())),
+                        (address_of!(alice), Some(token_2.clone())),
+                        (address_of!(bob), Some(token_1.clone())),
+                        (address_of!(alice), None),
+                    ])
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(balances, Ok(vec![amount_1, amount_2, 0, 2]));
+
+            let supplies = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.total_supply_batch(vec![
+                        Some(token_1.clone()),
+                        Some(token_2.clone()),
+                        Some(Id::U8(2)),
+                        None,
+                    ])
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(supplies, Ok(vec![amount_1, amount_2, 0, 2]));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn too_large_batch_query_should_fail(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_batch", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let supplies = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.total_supply_batch(vec![None; 101]));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert!(matches!(supplies, Err(_)));
+
+            let batch_transfer_multi_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract
+                        .batch_transfer_multi(vec![(address_of!(bob), Id::U8(0), 0); 101], vec![])
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                batch_transfer_multi_tx,
+                Err(AFT37Error::Custom(String::from("BatchTooLarge")))
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_multi_overflow_should_fail(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_batch", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token = Id::U8(0);
+
+            let batch_transfer_multi_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.batch_transfer_multi(
+                        vec![
+                            (address_of!(bob), token.clone(), Balance::MAX),
+                            (address_of!(charlie), token.clone(), 1),
+                        ],
+                        vec![],
+                    )
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                batch_transfer_multi_tx,
+                Err(AFT37Error::Custom(String::from("Overflow")))
+            );
+
+            Ok(())
+        }
     }
 }
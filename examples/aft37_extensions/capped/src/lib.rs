@@ -0,0 +1,93 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[allfeat_contracts::implementation(AFT37, AFT37Capped, AFT37Mintable)]
+#[allfeat_contracts::contract]
+pub mod my_aft37_capped {
+    use openbrush::traits::Storage;
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft37: aft37::Data,
+        #[storage_field]
+        capped: capped::Data,
+    }
+
+    impl Contract {
+        /// contract constructor
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Limits the edition of token type `id` to `cap` tokens
+        #[ink(message)]
+        pub fn set_max_supply(&mut self, id: Id, cap: Balance) -> Result<(), AFT37Error> {
+            capped::Internal::_set_max_supply(self, id, cap)
+        }
+    }
+
+    #[cfg(test)]
+    pub mod tests {
+        use allfeat_contracts::aft37::AFT37Error::*;
+        use ink::env::test;
+        use openbrush::traits::String;
+
+        #[rustfmt::skip]
+        use super::*;
+
+        #[ink::test]
+        fn max_supply_is_enforced() {
+            let accounts = test::default_accounts::<Environment>();
+            let mut aft37 = Contract::new();
+            let edition = Id::U8(1);
+            let open = Id::U8(2);
+
+            assert!(aft37.set_max_supply(edition.clone(), 3).is_ok());
+            assert_eq!(
+                AFT37CappedImpl::max_supply(&aft37, edition.clone()),
+                Some(3)
+            );
+            assert_eq!(AFT37CappedImpl::max_supply(&aft37, open.clone()), None);
+
+            assert!(AFT37MintableImpl::mint(
+                &mut aft37,
+                accounts.alice,
+                vec![(edition.clone(), 2)]
+            )
+            .is_ok());
+            assert_eq!(
+                AFT37MintableImpl::mint(
+                    &mut aft37,
+                    accounts.bob,
+                    vec![(edition.clone(), 1), (edition.clone(), 1)]
+                ),
+                Err(MaxSupplyExceeded)
+            );
+            assert!(
+                AFT37MintableImpl::mint(&mut aft37, accounts.bob, vec![(edition.clone(), 1)])
+                    .is_ok()
+            );
+            assert_eq!(
+                AFT37MintableImpl::mint(&mut aft37, accounts.bob, vec![(edition.clone(), 1)]),
+                Err(MaxSupplyExceeded)
+            );
+            assert_eq!(AFT37Impl::total_supply(&aft37, Some(edition.clone())), 3);
+
+            assert!(
+                AFT37MintableImpl::mint(&mut aft37, accounts.bob, vec![(open.clone(), 1_000)])
+                    .is_ok()
+            );
+
+            assert_eq!(
+                aft37.set_max_supply(edition.clone(), 2),
+                Err(MaxSupplyExceeded)
+            );
+            assert_eq!(
+                aft37.set_max_supply(open, 0),
+                Err(Custom(String::from("Cap must be above 0")))
+            );
+        }
+    }
+}
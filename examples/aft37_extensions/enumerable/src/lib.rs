@@ -390,5 +390,117 @@ pub mod my_aft37_enumerable {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn holders_enumeration_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate(
+                    "my_aft37_enumerable",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token = Id::U8(0);
+
+            let mint_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.mint(address_of!(alice), vec![(token.clone(), 10)]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+
+            let transfer_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(address_of!(bob), token.clone(), 4, vec![]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            }
+            .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+
+            let holder_count = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.holder_count(token.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let holders = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.holders_of(token.clone(), 0, 10));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(holder_count, 2);
+            assert_eq!(holders, vec![address_of!(alice), address_of!(bob)]);
+
+            // alice's balance crosses zero, she is no longer a holder
+            let transfer_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.transfer(address_of!(bob), token.clone(), 6, vec![]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("transfer failed")
+            }
+            .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+
+            let holder_count = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.holder_count(token.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let holder_by_index = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.holder_by_index(token.clone(), 0));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(holder_count, 1);
+            assert_eq!(holder_by_index, Some(address_of!(bob)));
+
+            let burn_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.burn(address_of!(bob), vec![(token.clone(), 10)]));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("burn failed")
+            }
+            .return_value();
+
+            assert_eq!(burn_tx, Ok(()));
+
+            let holder_count = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.holder_count(token.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(holder_count, 0);
+
+            Ok(())
+        }
     }
 }
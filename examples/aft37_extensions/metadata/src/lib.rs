@@ -30,6 +30,20 @@ pub mod my_aft37_metadata {
         ) -> Result<(), AFT37Error> {
             metadata::Internal::_set_attribute(self, &id, &key, &data)
         }
+
+        #[ink(message)]
+        pub fn set_attributes(
+            &mut self,
+            id: Id,
+            attributes: Vec<(String, String)>,
+        ) -> Result<(), AFT37Error> {
+            metadata::Internal::_set_attributes(self, &id, &attributes)
+        }
+
+        #[ink(message)]
+        pub fn remove_attribute(&mut self, id: Id, key: String) -> Result<(), AFT37Error> {
+            metadata::Internal::_remove_attribute(self, &id, &key)
+        }
     }
 
     #[cfg(all(test, feature = "e2e-tests"))]
@@ -95,5 +109,82 @@ pub mod my_aft37_metadata {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn attributes_management_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_metadata", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let id = Id::U8(0);
+            let producer = (String::from("producer"), String::from("Alice"));
+            let mixing = (String::from("mixing_engineer"), String::from("Bob"));
+            let isrc = (String::from("isrc"), String::from("FR-Z03-23-00001"));
+
+            let set_attributes_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_attributes(
+                        id.clone(),
+                        vec![producer.clone(), mixing.clone(), isrc.clone()],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("call failed")
+            }
+            .return_value();
+
+            assert_eq!(set_attributes_tx, Ok(()));
+
+            let attributes = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.get_attributes(id.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                attributes,
+                vec![producer.clone(), mixing.clone(), isrc.clone()]
+            );
+
+            let remove_attribute_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.remove_attribute(id.clone(), mixing.0.clone()));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("call failed")
+            }
+            .return_value();
+
+            assert_eq!(remove_attribute_tx, Ok(()));
+
+            let keys = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.attribute_keys(id.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(keys.len(), 2);
+            assert!(keys.contains(&producer.0));
+            assert!(keys.contains(&isrc.0));
+
+            let attribute = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.get_attribute(id.clone(), mixing.0.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(attribute, None);
+
+            Ok(())
+        }
     }
 }
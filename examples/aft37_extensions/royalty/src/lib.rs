@@ -0,0 +1,265 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[allfeat_contracts::implementation(AFT37, AFT37Royalty, AFT37Mintable)]
+#[allfeat_contracts::contract]
+pub mod my_aft37_royalty {
+    use openbrush::traits::Storage;
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct Contract {
+        #[storage_field]
+        aft37: aft37::Data,
+        #[storage_field]
+        royalty: royalty::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn set_default_royalty(
+            &mut self,
+            receiver: AccountId,
+            royalty_fraction: u16,
+        ) -> Result<(), AFT37Error> {
+            royalty::Internal::_set_default_royalty(self, receiver, royalty_fraction)
+        }
+
+        #[ink(message)]
+        pub fn set_token_royalty(
+            &mut self,
+            id: Id,
+            receiver: AccountId,
+            royalty_fraction: u16,
+        ) -> Result<(), AFT37Error> {
+            royalty::Internal::_set_token_royalty(self, id, receiver, royalty_fraction)
+        }
+
+        #[ink(message)]
+        pub fn set_royalty_split(
+            &mut self,
+            id: Option<Id>,
+            shares: Vec<(AccountId, u16)>,
+        ) -> Result<(), AFT37Error> {
+            royalty::Internal::_set_royalty_split(self, id, shares)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        use allfeat_contracts::aft37::extensions::royalty::aft37royalty_external::AFT37Royalty;
+
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::build_message;
+
+        use test_helpers::address_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn default_royalty_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_royalty", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token = Id::U8(0);
+
+            let royalty_info = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.royalty_info(token.clone(), 10_000));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(royalty_info, None);
+
+            let set_default_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_default_royalty(address_of!(bob), 500));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set default royalty failed")
+            }
+            .return_value();
+
+            assert_eq!(set_default_tx, Ok(()));
+
+            let royalty_info = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.royalty_info(token.clone(), 10_000));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(royalty_info, Some((address_of!(bob), 500)));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn token_royalty_overrides_default(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_royalty", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token_1 = Id::U8(0);
+            let token_2 = Id::U8(1);
+
+            let set_default_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_default_royalty(address_of!(bob), 500));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set default royalty failed")
+            }
+            .return_value();
+
+            assert_eq!(set_default_tx, Ok(()));
+
+            let set_token_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_token_royalty(token_2.clone(), address_of!(charlie), 1_000)
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set token royalty failed")
+            }
+            .return_value();
+
+            assert_eq!(set_token_tx, Ok(()));
+
+            let royalty_info_1 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.royalty_info(token_1.clone(), 1_000));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            let royalty_info_2 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.royalty_info(token_2.clone(), 1_000));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(royalty_info_1, Some((address_of!(bob), 50)));
+            assert_eq!(royalty_info_2, Some((address_of!(charlie), 100)));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn royalty_split_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_royalty", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let token = Id::U8(0);
+
+            let set_token_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_token_royalty(token.clone(), address_of!(charlie), 1_000)
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set token royalty failed")
+            }
+            .return_value();
+
+            assert_eq!(set_token_tx, Ok(()));
+
+            let set_split_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_royalty_split(
+                        Some(token.clone()),
+                        vec![
+                            (address_of!(bob), 5_000),
+                            (address_of!(charlie), 3_000),
+                            (address_of!(dave), 2_000),
+                        ],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set royalty split failed")
+            }
+            .return_value();
+
+            assert_eq!(set_split_tx, Ok(()));
+
+            let royalty_split = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.royalty_split(token.clone(), 1_000));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                royalty_split,
+                vec![
+                    (address_of!(bob), 50),
+                    (address_of!(charlie), 30),
+                    (address_of!(dave), 20),
+                ]
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn invalid_royalty_should_fail(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let constructor = ContractRef::new();
+            let address = client
+                .instantiate("my_aft37_royalty", &ink_e2e::alice(), constructor, 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let set_default_tx = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_default_royalty(address_of!(bob), 10_001));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert!(matches!(set_default_tx, Err(_)));
+
+            let set_split_tx = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.set_royalty_split(
+                        None,
+                        vec![(address_of!(bob), 5_000), (address_of!(charlie), 4_000)],
+                    )
+                });
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert!(matches!(set_split_tx, Err(_)));
+
+            Ok(())
+        }
+    }
+}
@@ -202,5 +202,86 @@ pub mod my_aft37_uri_storage {
 
             Ok(())
         }
+
+        #[ink_e2e::test]
+        async fn templated_base_uri_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let id_1 = Id::U8(10);
+            let id_2 = Id::U8(11);
+            let base = Some(URI::from("https://allfeat.network/{id}.json"));
+            let uri = URI::from("ipfs://aft37_11");
+
+            let constructor = ContractRef::new(base.clone());
+            let address = client
+                .instantiate(
+                    "my_aft37_uri_storage",
+                    &ink_e2e::alice(),
+                    constructor,
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            let result_token_uri_1 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_1.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            // Not minted yet
+            assert_eq!(result_token_uri_1, Err(AFT37Error::TokenNotExists));
+
+            let _mint = {
+                let _msg = build_message::<ContractRef>(address.clone()).call(|contract| {
+                    contract.mint(
+                        address_of!(alice),
+                        vec![(id_1.clone(), 1), (id_2.clone(), 1)],
+                    )
+                });
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("mint failed")
+            }
+            .return_value();
+
+            let result_set_token_uri = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.set_token_uri(id_2.clone(), uri.clone()));
+                client
+                    .call(&ink_e2e::alice(), _msg, 0, None)
+                    .await
+                    .expect("set call failed")
+            }
+            .return_value();
+
+            assert_eq!(result_set_token_uri, Ok(()));
+
+            let result_token_uri_1 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_1.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            assert_eq!(
+                result_token_uri_1,
+                Ok(Some(URI::from("https://allfeat.network/10.json")))
+            );
+
+            let result_token_uri_2 = {
+                let _msg = build_message::<ContractRef>(address.clone())
+                    .call(|contract| contract.token_uri(id_2.clone()));
+                client.call_dry_run(&ink_e2e::alice(), &_msg, 0, None).await
+            }
+            .return_value();
+
+            // The per-id URI takes precedence
+            assert_eq!(result_token_uri_2, Ok(Some(uri)));
+
+            Ok(())
+        }
     }
 }
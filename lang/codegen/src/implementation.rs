@@ -65,6 +65,10 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
 
     let mut impl_args = ImplArgs::new(&map, &mut items, &mut imports, &mut overriden_traits, ident);
     let is_capped = args.contains(&"AFT22Capped".to_string());
+    let is_soulbound = args.contains(&"AFT34Soulbound".to_string());
+    let is_rentable = args.contains(&"AFT34Rentable".to_string());
+    let is_nestable = args.contains(&"AFT34Nestable".to_string());
+    let is_aft37_capped = args.contains(&"AFT37Capped".to_string());
 
     for to_implement in args.clone() {
         match to_implement.as_str() {
@@ -76,18 +80,26 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "AFT22Wrapper" => impl_aft22_wrapper(&mut impl_args),
             "Flashmint" => impl_flashmint(&mut impl_args),
             "AFT22TokenTimelock" => impl_token_timelock(&mut impl_args),
-            "AFT34" => impl_aft34(&mut impl_args),
+            "AFT34" => impl_aft34(&mut impl_args, is_soulbound, is_rentable, is_nestable),
             "AFT34Burnable" => impl_aft34_burnable(&mut impl_args),
             "AFT34Metadata" => impl_aft34_metadata(&mut impl_args),
             "AFT34Enumerable" => impl_aft34_enumerable(&mut impl_args),
+            "AFT34LazyMint" => impl_aft34_lazy_mint(&mut impl_args),
             "AFT34Mintable" => impl_aft34_mintable(&mut impl_args),
+            "AFT34Nestable" => impl_aft34_nestable(&mut impl_args),
+            "AFT34OnchainMetadata" => impl_aft34_onchain_metadata(&mut impl_args),
             "AFT34PayableMint" => impl_aft34_payable_mint(&mut impl_args),
+            "AFT34Rentable" => impl_aft34_rentable(&mut impl_args),
+            "AFT34Reveal" => impl_aft34_reveal(&mut impl_args),
+            "AFT34Soulbound" => impl_aft34_soulbound(&mut impl_args),
             "AFT34URIStorage" => impl_aft34_uri_storage(&mut impl_args),
-            "AFT37" => impl_aft37(&mut impl_args),
+            "AFT37" => impl_aft37(&mut impl_args, is_aft37_capped),
             "AFT37Batch" => impl_aft37_batch(&mut impl_args),
             "AFT37Burnable" => impl_aft37_burnable(&mut impl_args),
+            "AFT37Capped" => impl_aft37_capped(&mut impl_args),
             "AFT37Metadata" => impl_aft37_metadata(&mut impl_args),
             "AFT37Mintable" => impl_aft37_mintable(&mut impl_args),
+            "AFT37Royalty" => impl_aft37_royalty(&mut impl_args),
             "AFT37Enumerable" => impl_aft37_enumerable(&mut impl_args),
             "AFT37URIStorage" => impl_aft37_uri_storage(&mut impl_args),
             _ => panic!("allfeat_contracts::implementation({to_implement}) not implemented!"),
@@ -141,15 +153,23 @@ fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
         "AFT34Metadata",
         "AFT34Enumerable",
         "AFT34URIStorage",
+        "AFT34OnchainMetadata",
+        "AFT34Reveal",
+        "AFT34Soulbound",
+        "AFT34Rentable",
+        "AFT34LazyMint",
+        "AFT34Nestable",
     ];
     check_and_remove_import("AFT34", aft34_impls, imports);
 
     let aft37_impls = vec![
         "AFT37Batch",
         "AFT37Burnable",
+        "AFT37Capped",
         "AFT37Metadata",
         "AFT37Mintable",
         "AFT37Enumerable",
+        "AFT37Royalty",
     ];
     check_and_remove_import("AFT37", aft37_impls, imports);
 
@@ -644,25 +644,55 @@ pub(crate) fn impl_token_timelock(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(timelock));
 }
 
-pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_aft34(
+    impl_args: &mut ImplArgs,
+    soulbound: bool,
+    rentable: bool,
+    nestable: bool,
+) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl aft34::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
+    // transfer hooks of the extensions, run before the default ones
+    let mut transfer_hooks = Vec::new();
+    if soulbound {
+        transfer_hooks.push(quote! {soulbound::AFT34TransferImpl});
+    }
+    if rentable {
+        transfer_hooks.push(quote! {rentable::AFT34TransferImpl});
+    }
+    if nestable {
+        transfer_hooks.push(quote! {nestable::AFT34TransferImpl});
+    }
+
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl aft34::Internal for #storage_struct_name {
             fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
                 aft34::InternalImpl::_emit_transfer_event(self, from, to, id)
             }
 
-            fn _emit_approval_event(&self, from: AccountId, to: AccountId, id: Option<Id>, approved: bool) {
-                aft34::InternalImpl::_emit_approval_event(self, from, to, id, approved)
+            fn _emit_approval_event(
+                &self,
+                from: AccountId,
+                to: AccountId,
+                id: Option<Id>,
+                approved: bool,
+                expires: Option<Timestamp>,
+            ) {
+                aft34::InternalImpl::_emit_approval_event(self, from, to, id, approved, expires)
             }
 
-            fn _approve_for(&mut self, to: AccountId, id: Option<Id>, approved: bool) -> Result<(), AFT34Error> {
-                aft34::InternalImpl::_approve_for(self, to, id, approved)
+            fn _approve_for(
+                &mut self,
+                to: AccountId,
+                id: Option<Id>,
+                approved: bool,
+                expires: Option<Timestamp>,
+            ) -> Result<(), AFT34Error> {
+                aft34::InternalImpl::_approve_for(self, to, id, approved, expires)
             }
 
             fn _owner_of(&self, id: &Id) -> Option<AccountId> {
@@ -673,10 +703,22 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
                 aft34::InternalImpl::_transfer_token(self, to, id, data)
             }
 
-            fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error> {
+            fn _id_policy(&self) -> IdPolicy {
+                aft34::InternalImpl::_id_policy(self)
+            }
+
+            fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<Id, AFT34Error> {
                 aft34::InternalImpl::_mint_to(self, to, id)
             }
 
+            fn _mint_consecutive(&mut self, to: AccountId, first_id: Id, amount: u128) -> Result<(), AFT34Error> {
+                aft34::InternalImpl::_mint_consecutive(self, to, first_id, amount)
+            }
+
+            fn _emit_consecutive_transfer_event(&self, from_id: Id, to_id: Id, to: AccountId) {
+                aft34::InternalImpl::_emit_consecutive_transfer_event(self, from_id, to_id, to)
+            }
+
             fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), AFT34Error> {
                 aft34::InternalImpl::_burn_from(self, from, id)
             }
@@ -685,6 +727,10 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
                 aft34::InternalImpl::_allowance(self, owner, operator, id)
             }
 
+            fn _approval(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<Option<Timestamp>> {
+                aft34::InternalImpl::_approval(self, owner, operator, id)
+            }
+
             fn _check_token_exists(&self, id: &Id) -> Result<AccountId, AFT34Error> {
                 aft34::InternalImpl::_check_token_exists(self, id)
             }
@@ -695,6 +741,7 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
                 to: Option<&AccountId>,
                 id: &Id,
             ) -> Result<(), AFT34Error> {
+                #(#transfer_hooks::_before_token_transfer(self, from, to, id)?;)*
                 aft34::InternalImpl::_before_token_transfer(self, from, to, id)
             }
 
@@ -704,6 +751,7 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
                 to: Option<&AccountId>,
                 id: &Id,
             ) -> Result<(), AFT34Error> {
+                #(#transfer_hooks::_after_token_transfer(self, from, to, id)?;)*
                 aft34::InternalImpl::_after_token_transfer(self, from, to, id)
             }
         }
@@ -737,11 +785,26 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
                 AFT34Impl::allowance(self, owner, operator, id)
             }
 
+            #[ink(message)]
+            fn approval_expiry(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> Option<Option<Timestamp>> {
+                AFT34Impl::approval_expiry(self, owner, operator, id)
+            }
+
+            #[ink(message)]
+            fn get_approved(&self, id: Id) -> Option<AccountId> {
+                AFT34Impl::get_approved(self, id)
+            }
+
             #[ink(message)]
             fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), AFT34Error> {
                 AFT34Impl::approve(self, operator, id, approved)
             }
 
+            #[ink(message)]
+            fn approve_until(&mut self, operator: AccountId, id: Option<Id>, expires: Timestamp) -> Result<(), AFT34Error> {
+                AFT34Impl::approve_until(self, operator, id, expires)
+            }
+
             #[ink(message)]
             fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), AFT34Error> {
                 AFT34Impl::transfer(self, to, id, data)
@@ -782,18 +845,36 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
                 aft34::BalancesManagerImpl::_owner_of(self, id)
             }
 
-            fn _operator_approvals(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<()> {
+            fn _operator_approvals(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<Option<Timestamp>> {
                 aft34::BalancesManagerImpl::_operator_approvals(self, owner, operator, id)
             }
 
-            fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
-                aft34::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id)
+            fn _insert_operator_approvals(
+                &mut self,
+                owner: &Owner,
+                operator: &Operator,
+                id: &Option<&Id>,
+                expires: &Option<Timestamp>,
+            ) {
+                aft34::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id, expires)
             }
 
             fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
                 aft34::BalancesManagerImpl::_remove_operator_approvals(self, owner, operator, id)
             }
 
+            fn _token_approval(&self, id: &Id) -> Option<(Operator, Option<Timestamp>)> {
+                aft34::BalancesManagerImpl::_token_approval(self, id)
+            }
+
+            fn _insert_token_approval(&mut self, id: &Id, operator: &Operator, expires: &Option<Timestamp>) {
+                aft34::BalancesManagerImpl::_insert_token_approval(self, id, operator, expires)
+            }
+
+            fn _remove_token_approval(&mut self, id: &Id) {
+                aft34::BalancesManagerImpl::_remove_token_approval(self, id)
+            }
+
             fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
                 aft34::BalancesManagerImpl::_insert_token_owner(self, id, to)
             }
@@ -801,6 +882,14 @@ pub(crate) fn impl_aft34(impl_args: &mut ImplArgs) {
             fn _remove_token_owner(&mut self, id: &Id) {
                 aft34::BalancesManagerImpl::_remove_token_owner(self, id)
             }
+
+            fn _insert_consecutive_batch(&mut self, batch: ConsecutiveBatch) -> Result<(), AFT34Error> {
+                aft34::BalancesManagerImpl::_insert_consecutive_batch(self, batch)
+            }
+
+            fn _max_consecutive_batch_size(&self) -> Option<u128> {
+                aft34::BalancesManagerImpl::_max_consecutive_batch_size(self)
+            }
         }
     ))
         .expect("Should parse");
@@ -914,7 +1003,112 @@ pub(crate) fn impl_aft34_mintable(impl_args: &mut ImplArgs) {
 pub(crate) fn impl_aft34_payable_mint(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl aft34::extensions::payable_mint::Internal for #storage_struct_name {}
+        impl payable_mint::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl payable_mint::Internal for #storage_struct_name {
+            fn check_value(&self, transferred_value: u128, mint_amount: u64) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::check_value(self, transferred_value, mint_amount)
+            }
+
+            fn check_amount(&self, mint_amount: u64) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::check_amount(self, mint_amount)
+            }
+
+            fn token_exists(&self, id: Id) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::token_exists(self, id)
+            }
+
+            fn _mint_payable(&mut self, to: AccountId, mint_amount: u64, proof: Vec<[u8; 32]>) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_mint_payable(self, to, mint_amount, proof)
+            }
+
+            fn _check_phase(&self, wallet: &AccountId, mint_amount: u64, proof: &[[u8; 32]]) -> Result<Option<u32>, AFT34Error> {
+                payable_mint::InternalImpl::_check_phase(self, wallet, mint_amount, proof)
+            }
+
+            fn _set_phase(&mut self, phase_id: u32, phase: SalePhase) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_set_phase(self, phase_id, phase)
+            }
+
+            fn _active_phase(&self) -> Option<(u32, SalePhase)> {
+                payable_mint::InternalImpl::_active_phase(self)
+            }
+
+            fn _current_price(&self) -> Balance {
+                payable_mint::InternalImpl::_current_price(self)
+            }
+
+            fn _remaining_allocation(&self, wallet: &AccountId) -> u64 {
+                payable_mint::InternalImpl::_remaining_allocation(self, wallet)
+            }
+
+            fn _collect_payment(&mut self, payer: AccountId, mint_amount: u64) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_collect_payment(self, payer, mint_amount)
+            }
+
+            fn _pull_payment(&mut self, payment_token: AccountId, payer: AccountId, amount: Balance) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_pull_payment(self, payment_token, payer, amount)
+            }
+
+            fn _push_payment(&mut self, payment_token: AccountId, to: AccountId, amount: Balance) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_push_payment(self, payment_token, to, amount)
+            }
+
+            fn _payee(&self) -> Result<AccountId, AFT34Error> {
+                payable_mint::InternalImpl::_payee(self)
+            }
+
+            fn _payouts(&self, amount: Balance) -> Result<Vec<(AccountId, Balance)>, AFT34Error> {
+                payable_mint::InternalImpl::_payouts(self, amount)
+            }
+
+            fn _refund(&mut self, to: AccountId, amount: Balance) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_refund(self, to, amount)
+            }
+
+            fn _withdraw_token(&mut self, payment_token: AccountId) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_withdraw_token(self, payment_token)
+            }
+
+            fn _withdraw_native(&mut self) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_withdraw_native(self)
+            }
+
+            fn _max_payout_splits(&self) -> u32 {
+                payable_mint::InternalImpl::_max_payout_splits(self)
+            }
+
+            fn _set_payout_splits(&mut self, splits: Vec<(AccountId, u32)>) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_set_payout_splits(self, splits)
+            }
+
+            fn _set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<(), AFT34Error> {
+                payable_mint::InternalImpl::_set_payment_token(self, payment_token)
+            }
+
+            fn _set_payout_address(&mut self, payout_address: Option<AccountId>) {
+                payable_mint::InternalImpl::_set_payout_address(self, payout_address)
+            }
+
+            fn _verify_allowlist(&self, merkle_root: &[u8; 32], wallet: &AccountId, proof: &[[u8; 32]]) -> bool {
+                payable_mint::InternalImpl::_verify_allowlist(self, merkle_root, wallet, proof)
+            }
+
+            fn _set_base_uri(&mut self, base_uri: Option<PreludeString>) {
+                payable_mint::InternalImpl::_set_base_uri(self, base_uri)
+            }
+
+            fn _set_uri_suffix(&mut self, uri_suffix: Option<PreludeString>) {
+                payable_mint::InternalImpl::_set_uri_suffix(self, uri_suffix)
+            }
+
+            fn _render_id(&self, id: &Id) -> PreludeString {
+                payable_mint::InternalImpl::_render_id(self, id)
+            }
+        }
     ))
     .expect("Should parse");
 
@@ -930,6 +1124,11 @@ pub(crate) fn impl_aft34_payable_mint(impl_args: &mut ImplArgs) {
                 AFT34PayableMintImpl::mint(self, to, mint_amount)
             }
 
+            #[ink(message, payable)]
+            fn mint_allowlisted(&mut self, to: AccountId, mint_amount: u64, proof: Vec<[u8; 32]>) -> Result<(), AFT34Error> {
+                AFT34PayableMintImpl::mint_allowlisted(self, to, mint_amount, proof)
+            }
+
             #[ink(message)]
             fn withdraw(&mut self) -> Result<(), AFT34Error> {
                 AFT34PayableMintImpl::withdraw(self)
@@ -941,7 +1140,17 @@ pub(crate) fn impl_aft34_payable_mint(impl_args: &mut ImplArgs) {
             }
 
             #[ink(message)]
-            fn token_uri(&self, token_id: u64) -> Result<PreludeString, AFT34Error> {
+            fn set_uri_suffix(&mut self, uri_suffix: Option<PreludeString>) -> Result<(), AFT34Error> {
+                AFT34PayableMintImpl::set_uri_suffix(self, uri_suffix)
+            }
+
+            #[ink(message)]
+            fn base_uri(&self) -> Option<PreludeString> {
+                AFT34PayableMintImpl::base_uri(self)
+            }
+
+            #[ink(message)]
+            fn token_uri(&self, token_id: Id) -> Result<Option<PreludeString>, AFT34Error> {
                 AFT34PayableMintImpl::token_uri(self, token_id)
             }
 
@@ -954,6 +1163,61 @@ pub(crate) fn impl_aft34_payable_mint(impl_args: &mut ImplArgs) {
             fn price(&self) -> Balance {
                 AFT34PayableMintImpl::price(self)
             }
+
+            #[ink(message)]
+            fn set_phase(&mut self, phase_id: u32, phase: SalePhase) -> Result<(), AFT34Error> {
+                AFT34PayableMintImpl::set_phase(self, phase_id, phase)
+            }
+
+            #[ink(message)]
+            fn phase(&self, phase_id: u32) -> Option<SalePhase> {
+                AFT34PayableMintImpl::phase(self, phase_id)
+            }
+
+            #[ink(message)]
+            fn phases_count(&self) -> u32 {
+                AFT34PayableMintImpl::phases_count(self)
+            }
+
+            #[ink(message)]
+            fn active_phase(&self) -> Option<(u32, SalePhase)> {
+                AFT34PayableMintImpl::active_phase(self)
+            }
+
+            #[ink(message)]
+            fn remaining_allocation(&self, wallet: AccountId) -> u64 {
+                AFT34PayableMintImpl::remaining_allocation(self, wallet)
+            }
+
+            #[ink(message)]
+            fn set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<(), AFT34Error> {
+                AFT34PayableMintImpl::set_payment_token(self, payment_token)
+            }
+
+            #[ink(message)]
+            fn payment_token(&self) -> Option<AccountId> {
+                AFT34PayableMintImpl::payment_token(self)
+            }
+
+            #[ink(message)]
+            fn set_payout_address(&mut self, payout_address: Option<AccountId>) -> Result<(), AFT34Error> {
+                AFT34PayableMintImpl::set_payout_address(self, payout_address)
+            }
+
+            #[ink(message)]
+            fn payout_address(&self) -> Option<AccountId> {
+                AFT34PayableMintImpl::payout_address(self)
+            }
+
+            #[ink(message)]
+            fn set_payout_splits(&mut self, splits: Vec<(AccountId, u32)>) -> Result<(), AFT34Error> {
+                AFT34PayableMintImpl::set_payout_splits(self, splits)
+            }
+
+            #[ink(message)]
+            fn payout_splits(&self) -> Vec<(AccountId, u32)> {
+                AFT34PayableMintImpl::payout_splits(self)
+            }
         }
     ))
     .expect("Should parse");
@@ -965,9 +1229,11 @@ pub(crate) fn impl_aft34_payable_mint(impl_args: &mut ImplArgs) {
     impl_args.imports.insert("AFT34PayableMint", import);
     impl_args.vec_import();
 
+    override_functions("payable_mint::Internal", &mut internal, impl_args.map);
     override_functions("AFT34PayableMint", &mut payable_mint, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(payable_mint_impl));
     impl_args.items.push(syn::Item::Impl(payable_mint));
 }
@@ -985,9 +1251,49 @@ pub(crate) fn impl_aft34_metadata(impl_args: &mut ImplArgs) {
                 metadata::InternalImpl::_emit_attribute_set_event(self, id, key, data)
             }
 
-            fn _set_attribute(&mut self, id: Id, key: String, value: String) {
+            fn _emit_attribute_removed_event(&self, id: Id, key: String) {
+                metadata::InternalImpl::_emit_attribute_removed_event(self, id, key)
+            }
+
+            fn _emit_metadata_update_event(&self, id: Id) {
+                metadata::InternalImpl::_emit_metadata_update_event(self, id)
+            }
+
+            fn _emit_batch_metadata_update_event(&self, from_id: Id, to_id: Id) {
+                metadata::InternalImpl::_emit_batch_metadata_update_event(self, from_id, to_id)
+            }
+
+            fn _emit_metadata_frozen_event(&self, id: Option<Id>) {
+                metadata::InternalImpl::_emit_metadata_frozen_event(self, id)
+            }
+
+            fn _set_attribute(&mut self, id: Id, key: String, value: String) -> Result<(), AFT34Error> {
                 metadata::InternalImpl::_set_attribute(self, id, key, value)
             }
+
+            fn _set_attributes(&mut self, id: Id, attributes: Vec<(String, String)>) -> Result<(), AFT34Error> {
+                metadata::InternalImpl::_set_attributes(self, id, attributes)
+            }
+
+            fn _remove_attribute(&mut self, id: Id, key: String) -> Result<(), AFT34Error> {
+                metadata::InternalImpl::_remove_attribute(self, id, key)
+            }
+
+            fn _attribute_keys(&self, id: &Id) -> Vec<String> {
+                metadata::InternalImpl::_attribute_keys(self, id)
+            }
+
+            fn _metadata_frozen(&self, id: &Id) -> bool {
+                metadata::InternalImpl::_metadata_frozen(self, id)
+            }
+
+            fn _freeze_metadata(&mut self, id: Id) {
+                metadata::InternalImpl::_freeze_metadata(self, id)
+            }
+
+            fn _freeze_all(&mut self) {
+                metadata::InternalImpl::_freeze_all(self)
+            }
         }
     ))
     .expect("Should parse");
@@ -1003,6 +1309,21 @@ pub(crate) fn impl_aft34_metadata(impl_args: &mut ImplArgs) {
             fn get_attribute(&self, id: Id, key: String) -> Option<String> {
                 AFT34MetadataImpl::get_attribute(self, id, key)
             }
+
+            #[ink(message)]
+            fn get_attributes(&self, id: Id) -> Vec<(String, String)> {
+                AFT34MetadataImpl::get_attributes(self, id)
+            }
+
+            #[ink(message)]
+            fn attribute_keys(&self, id: Id) -> Vec<String> {
+                AFT34MetadataImpl::attribute_keys(self, id)
+            }
+
+            #[ink(message)]
+            fn is_metadata_frozen(&self, id: Id) -> bool {
+                AFT34MetadataImpl::is_metadata_frozen(self, id)
+            }
         }
     ))
     .expect("Should parse");
@@ -1025,6 +1346,24 @@ pub(crate) fn impl_aft34_metadata(impl_args: &mut ImplArgs) {
 
 pub(crate) fn impl_aft34_enumerable(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::Internal for #storage_struct_name {
+            fn _max_page_size(&self) -> u128 {
+                enumerable::InternalImpl::_max_page_size(self)
+            }
+
+            fn _tokens_page(&self, owner: &Option<&AccountId>, offset: u128, limit: u128) -> Vec<Id> {
+                enumerable::InternalImpl::_tokens_page(self, owner, offset, limit)
+            }
+        }
+    ))
+    .expect("Should parse");
+
     let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl AFT34EnumerableImpl for #storage_struct_name {}
     ))
@@ -1041,6 +1380,16 @@ pub(crate) fn impl_aft34_enumerable(impl_args: &mut ImplArgs) {
             fn token_by_index(&self, index: u128) -> Result<Id, AFT34Error> {
                 AFT34EnumerableImpl::token_by_index(self, index)
             }
+
+            #[ink(message)]
+            fn tokens_of_owner(&self, owner: AccountId, offset: u128, limit: u128) -> Vec<Id> {
+                AFT34EnumerableImpl::tokens_of_owner(self, owner, offset, limit)
+            }
+
+            #[ink(message)]
+            fn tokens(&self, offset: u128, limit: u128) -> Vec<Id> {
+                AFT34EnumerableImpl::tokens(self, offset, limit)
+            }
         }
 
     ))
@@ -1073,18 +1422,36 @@ pub(crate) fn impl_aft34_enumerable(impl_args: &mut ImplArgs) {
                 enumerable::BalancesManagerImpl::_owner_of(self, id)
             }
 
-            fn _operator_approvals(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<()> {
+            fn _operator_approvals(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> Option<Option<Timestamp>> {
                 enumerable::BalancesManagerImpl::_operator_approvals(self, owner, operator, id)
             }
 
-            fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
-                enumerable::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id)
+            fn _insert_operator_approvals(
+                &mut self,
+                owner: &Owner,
+                operator: &Operator,
+                id: &Option<&Id>,
+                expires: &Option<Timestamp>,
+            ) {
+                enumerable::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id, expires)
             }
 
             fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
                 enumerable::BalancesManagerImpl::_remove_operator_approvals(self, owner, operator, id)
             }
 
+            fn _token_approval(&self, id: &Id) -> Option<(Operator, Option<Timestamp>)> {
+                enumerable::BalancesManagerImpl::_token_approval(self, id)
+            }
+
+            fn _insert_token_approval(&mut self, id: &Id, operator: &Operator, expires: &Option<Timestamp>) {
+                enumerable::BalancesManagerImpl::_insert_token_approval(self, id, operator, expires)
+            }
+
+            fn _remove_token_approval(&mut self, id: &Id) {
+                enumerable::BalancesManagerImpl::_remove_token_approval(self, id)
+            }
+
             fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
                 enumerable::BalancesManagerImpl::_insert_token_owner(self, id, to)
             }
@@ -1092,6 +1459,14 @@ pub(crate) fn impl_aft34_enumerable(impl_args: &mut ImplArgs) {
             fn _remove_token_owner(&mut self, id: &Id) {
                 enumerable::BalancesManagerImpl::_remove_token_owner(self, id)
             }
+
+            fn _insert_consecutive_batch(&mut self, batch: ConsecutiveBatch) -> Result<(), AFT34Error> {
+                enumerable::BalancesManagerImpl::_insert_consecutive_batch(self, batch)
+            }
+
+            fn _max_consecutive_batch_size(&self) -> Option<u128> {
+                enumerable::BalancesManagerImpl::_max_consecutive_batch_size(self)
+            }
         }
     ))
         .expect("Should parse");
@@ -1104,6 +1479,7 @@ pub(crate) fn impl_aft34_enumerable(impl_args: &mut ImplArgs) {
     impl_args.vec_import();
 
     override_functions("aft34::BalancesManager", &mut aft34_balances, impl_args.map);
+    override_functions("enumerable::Internal", &mut internal, impl_args.map);
     override_functions("aft34Enumerable", &mut aft34_enumerable, impl_args.map);
 
     impl_args
@@ -1114,6 +1490,8 @@ pub(crate) fn impl_aft34_enumerable(impl_args: &mut ImplArgs) {
         syn::Item::Impl(aft34_balances_impl),
     );
 
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(enumerable_impl));
     impl_args.items.push(syn::Item::Impl(aft34_enumerable));
 }
@@ -1134,14 +1512,58 @@ pub(crate) fn impl_aft34_uri_storage(impl_args: &mut ImplArgs) {
                 uri_storage::InternalImpl::_emit_attribute_set_base_event(self, base_uri)
             }
 
+            fn _emit_metadata_update_event(&self, token_id: Id) {
+                uri_storage::InternalImpl::_emit_metadata_update_event(self, token_id)
+            }
+
+            fn _emit_batch_metadata_update_event(&self, from_token_id: Id, to_token_id: Id) {
+                uri_storage::InternalImpl::_emit_batch_metadata_update_event(self, from_token_id, to_token_id)
+            }
+
+            fn _emit_metadata_frozen_event(&self, token_id: Option<Id>) {
+                uri_storage::InternalImpl::_emit_metadata_frozen_event(self, token_id)
+            }
+
             fn _set_token_uri(&mut self, token_id: Id, token_uri: URI) -> Result<(), AFT34Error> {
                 uri_storage::InternalImpl::_set_token_uri(self, token_id, token_uri)
             }
 
-            fn _set_base_uri(&mut self, base_uri: Option<URI>) {
+            fn _set_token_uri_batch(&mut self, token_uris: Vec<(Id, URI)>) -> Result<(), AFT34Error> {
+                uri_storage::InternalImpl::_set_token_uri_batch(self, token_uris)
+            }
+
+            fn _set_base_uri(&mut self, base_uri: Option<URI>) -> Result<(), AFT34Error> {
                 uri_storage::InternalImpl::_set_base_uri(self, base_uri)
             }
 
+            fn _set_uri_fallback(&mut self, enabled: bool) -> Result<(), AFT34Error> {
+                uri_storage::InternalImpl::_set_uri_fallback(self, enabled)
+            }
+
+            fn _set_uri_suffix(&mut self, uri_suffix: Option<URI>) -> Result<(), AFT34Error> {
+                uri_storage::InternalImpl::_set_uri_suffix(self, uri_suffix)
+            }
+
+            fn _render_id(&self, token_id: &Id) -> String {
+                uri_storage::InternalImpl::_render_id(self, token_id)
+            }
+
+            fn _metadata_frozen(&self, token_id: &Id) -> bool {
+                uri_storage::InternalImpl::_metadata_frozen(self, token_id)
+            }
+
+            fn _resolve_token_uri(&self, token_id: &Id) -> Option<URI> {
+                uri_storage::InternalImpl::_resolve_token_uri(self, token_id)
+            }
+
+            fn _freeze_metadata(&mut self, token_id: Id) -> Result<(), AFT34Error> {
+                uri_storage::InternalImpl::_freeze_metadata(self, token_id)
+            }
+
+            fn _freeze_all(&mut self) {
+                uri_storage::InternalImpl::_freeze_all(self)
+            }
+
             fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), AFT34Error> {
                 uri_storage::InternalImpl::_burn_from(self, from, id)
             }
@@ -1164,6 +1586,10 @@ pub(crate) fn impl_aft34_uri_storage(impl_args: &mut ImplArgs) {
             fn token_uri(&self, token_id: Id) -> Result<Option<URI>, AFT34Error> {
                 AFT34URIStorageImpl::token_uri(self, token_id)
             }
+            #[ink(message)]
+            fn is_metadata_frozen(&self, token_id: Id) -> bool {
+                AFT34URIStorageImpl::is_metadata_frozen(self, token_id)
+            }
         }
     ))
     .expect("Should parse");
@@ -1185,13 +1611,19 @@ pub(crate) fn impl_aft34_uri_storage(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(uri_storage));
 }
 
-pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_aft37(impl_args: &mut ImplArgs, capped: bool) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl aft37::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
+    // transfer hooks of the extensions, run before the default ones
+    let mut transfer_hooks = Vec::new();
+    if capped {
+        transfer_hooks.push(quote! {capped::AFT37TransferImpl});
+    }
+
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl aft37::Internal for #storage_struct_name {
             fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id, amount: Balance) {
@@ -1207,14 +1639,25 @@ pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
                 aft37::InternalImpl::_emit_transfer_batch_event(self, from, to, ids_amounts)
             }
 
-            fn _emit_approval_event(&self, owner: AccountId, operator: AccountId, id: Option<Id>, value: Balance) {
-                aft37::InternalImpl::_emit_approval_event(self, owner, operator, id, value)
+            fn _emit_approval_event(
+                &self,
+                owner: AccountId,
+                operator: AccountId,
+                id: Option<Id>,
+                value: Balance,
+                expires: Option<Timestamp>,
+            ) {
+                aft37::InternalImpl::_emit_approval_event(self, owner, operator, id, value, expires)
             }
 
             fn _token_exists(&self, id: &Id) -> Result<(), AFT37Error> {
                 aft37::InternalImpl::_token_exists(self, id)
             }
 
+            fn _id_policy(&self) -> IdPolicy {
+                aft37::InternalImpl::_id_policy(self)
+            }
+
             fn _mint_to(&mut self, to: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), AFT37Error> {
                 aft37::InternalImpl::_mint_to(self, to, ids_amounts)
             }
@@ -1238,8 +1681,23 @@ pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
                 aft37::InternalImpl::_get_allowance(self, account, operator, id)
             }
 
-            fn _approve_for(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), AFT37Error> {
-                aft37::InternalImpl::_approve_for(self, operator, id, value)
+            fn _approval(
+                &self,
+                owner: &AccountId,
+                operator: &AccountId,
+                id: &Option<&Id>,
+            ) -> Option<(Balance, Option<Timestamp>)> {
+                aft37::InternalImpl::_approval(self, owner, operator, id)
+            }
+
+            fn _approve_for(
+                &mut self,
+                operator: AccountId,
+                id: Option<Id>,
+                value: Balance,
+                expires: Option<Timestamp>,
+            ) -> Result<(), AFT37Error> {
+                aft37::InternalImpl::_approve_for(self, operator, id, value, expires)
             }
 
             fn _decrease_allowance(
@@ -1269,6 +1727,7 @@ pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
                 to: Option<&AccountId>,
                 ids: &[(Id, Balance)],
             ) -> Result<(), AFT37Error> {
+                #(#transfer_hooks::_before_token_transfer(self, from, to, ids)?;)*
                 aft37::InternalImpl::_before_token_transfer(self, from, to, ids)
             }
 
@@ -1278,6 +1737,7 @@ pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
                 to: Option<&AccountId>,
                 ids: &[(Id, Balance)],
             ) -> Result<(), AFT37Error> {
+                #(#transfer_hooks::_after_token_transfer(self, from, to, ids)?;)*
                 aft37::InternalImpl::_after_token_transfer(self, from, to, ids)
             }
         }
@@ -1307,11 +1767,27 @@ pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
                 AFT37Impl::allowance(self, owner, operator, id)
             }
 
+            #[ink(message)]
+            fn approval_expiry(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> Option<Option<Timestamp>> {
+                AFT37Impl::approval_expiry(self, owner, operator, id)
+            }
+
             #[ink(message)]
             fn approve(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), AFT37Error> {
                 AFT37Impl::approve(self, operator, id, value)
             }
 
+            #[ink(message)]
+            fn approve_until(
+                &mut self,
+                operator: AccountId,
+                id: Option<Id>,
+                value: Balance,
+                expires: Timestamp,
+            ) -> Result<(), AFT37Error> {
+                AFT37Impl::approve_until(self, operator, id, value, expires)
+            }
+
             #[ink(message)]
             fn transfer(&mut self, to: AccountId, id: Id, value: Balance, data: Vec<u8>) -> Result<(), AFT37Error> {
                 AFT37Impl::transfer(self, to, id, value, data)
@@ -1373,14 +1849,20 @@ pub(crate) fn impl_aft37(impl_args: &mut ImplArgs) {
                 operator: &AccountId,
                 id: &Option<&Id>,
                 amount: &Balance,
+                expires: &Option<Timestamp>,
             ) {
-                aft37::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id, amount)
+                aft37::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id, amount, expires)
             }
 
-            fn _get_operator_approvals(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>) -> Option<Balance> {
-                aft37::BalancesManagerImpl::_get_operator_approvals(self, owner, operator, id)
-            }
-            fn _remove_operator_approvals(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>) {
+            fn _get_operator_approvals(
+                &self,
+                owner: &AccountId,
+                operator: &AccountId,
+                id: &Option<&Id>,
+            ) -> Option<(Balance, Option<Timestamp>)> {
+                aft37::BalancesManagerImpl::_get_operator_approvals(self, owner, operator, id)
+            }
+            fn _remove_operator_approvals(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>) {
                 aft37::BalancesManagerImpl::_remove_operator_approvals(self, owner, operator, id)
             }
         }
@@ -1424,6 +1906,10 @@ pub(crate) fn impl_aft37_batch(impl_args: &mut ImplArgs) {
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl batch::Internal for #storage_struct_name {
+            fn _max_batch_size(&self) -> u32 {
+                batch::InternalImpl::_max_batch_size(self)
+            }
+
             fn _batch_transfer_from(
                 &mut self,
                 from: AccountId,
@@ -1433,6 +1919,15 @@ pub(crate) fn impl_aft37_batch(impl_args: &mut ImplArgs) {
             ) -> Result<(), AFT37Error> {
                 batch::InternalImpl::_batch_transfer_from(self, from, to, ids_amounts, data)
             }
+
+            fn _batch_transfer_multi_from(
+                &mut self,
+                from: AccountId,
+                transfers: Vec<(AccountId, Id, Balance)>,
+                data: Vec<u8>,
+            ) -> Result<(), AFT37Error> {
+                batch::InternalImpl::_batch_transfer_multi_from(self, from, transfers, data)
+            }
         }
     ))
     .expect("Should parse");
@@ -1464,6 +1959,38 @@ pub(crate) fn impl_aft37_batch(impl_args: &mut ImplArgs) {
             ) -> Result<(), AFT37Error> {
                 AFT37BatchImpl::batch_transfer_from(self, from, to, ids_amounts, data)
             }
+
+            #[ink(message)]
+            fn batch_transfer_multi(
+                &mut self,
+                transfers: Vec<(AccountId, Id, Balance)>,
+                data: Vec<u8>,
+            ) -> Result<(), AFT37Error> {
+                AFT37BatchImpl::batch_transfer_multi(self, transfers, data)
+            }
+
+            #[ink(message)]
+            fn batch_transfer_multi_from(
+                &mut self,
+                from: AccountId,
+                transfers: Vec<(AccountId, Id, Balance)>,
+                data: Vec<u8>,
+            ) -> Result<(), AFT37Error> {
+                AFT37BatchImpl::batch_transfer_multi_from(self, from, transfers, data)
+            }
+
+            #[ink(message)]
+            fn balance_of_batch(
+                &self,
+                owners_ids: Vec<(AccountId, Option<Id>)>,
+            ) -> Result<Vec<Balance>, AFT37Error> {
+                AFT37BatchImpl::balance_of_batch(self, owners_ids)
+            }
+
+            #[ink(message)]
+            fn total_supply_batch(&self, ids: Vec<Option<Id>>) -> Result<Vec<Balance>, AFT37Error> {
+                AFT37BatchImpl::total_supply_batch(self, ids)
+            }
         }
     ))
     .expect("Should parse");
@@ -1514,6 +2041,67 @@ pub(crate) fn impl_aft37_burnable(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(burnable));
 }
 
+pub(crate) fn impl_aft37_capped(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::Internal for #storage_struct_name {
+            fn _max_supply(&self, id: &Id) -> Option<Balance> {
+                capped::InternalImpl::_max_supply(self, id)
+            }
+
+            fn _set_max_supply(&mut self, id: Id, cap: Balance) -> Result<(), AFT37Error> {
+                capped::InternalImpl::_set_max_supply(self, id, cap)
+            }
+
+            fn _is_max_supply_exceeded(&self, id: &Id, amount: Balance) -> bool {
+                capped::InternalImpl::_is_max_supply_exceeded(self, id, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let transfer_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::AFT37TransferImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let capped_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT37CappedImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut capped = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT37Capped for #storage_struct_name {
+            #[ink(message)]
+            fn max_supply(&self, id: Id) -> Option<Balance> {
+                AFT37CappedImpl::max_supply(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft37::extensions::capped::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT37Capped", import);
+    impl_args.vec_import();
+
+    override_functions("capped::Internal", &mut internal, impl_args.map);
+    override_functions("AFT37Capped", &mut capped, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(transfer_impl));
+    impl_args.items.push(syn::Item::Impl(capped_impl));
+    impl_args.items.push(syn::Item::Impl(capped));
+}
+
 pub(crate) fn impl_aft37_metadata(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -1527,10 +2115,26 @@ pub(crate) fn impl_aft37_metadata(impl_args: &mut ImplArgs) {
                 metadata::InternalImpl::_emit_attribute_set_event(self, id, key, data);
             }
 
+            fn _emit_attribute_removed_event(&self, id: &Id, key: &String) {
+                metadata::InternalImpl::_emit_attribute_removed_event(self, id, key);
+            }
+
             fn _set_attribute(&mut self, id: &Id, key: &String, data: &String) -> Result<(), AFT37Error> {
                 metadata::InternalImpl::_set_attribute(self, id, key, data)
             }
 
+            fn _set_attributes(&mut self, id: &Id, attributes: &[(String, String)]) -> Result<(), AFT37Error> {
+                metadata::InternalImpl::_set_attributes(self, id, attributes)
+            }
+
+            fn _remove_attribute(&mut self, id: &Id, key: &String) -> Result<(), AFT37Error> {
+                metadata::InternalImpl::_remove_attribute(self, id, key)
+            }
+
+            fn _attribute_keys(&self, id: &Id) -> Vec<String> {
+                metadata::InternalImpl::_attribute_keys(self, id)
+            }
+
             fn _get_attribute(&self, id: &Id, key: &String) -> Option<String> {
                 metadata::InternalImpl::_get_attribute(self, id, key)
             }
@@ -1549,6 +2153,16 @@ pub(crate) fn impl_aft37_metadata(impl_args: &mut ImplArgs) {
             fn get_attribute(&self, id: Id, key: String) -> Option<String> {
                 AFT37MetadataImpl::get_attribute(self, id, key)
             }
+
+            #[ink(message)]
+            fn get_attributes(&self, id: Id) -> Vec<(String, String)> {
+                AFT37MetadataImpl::get_attributes(self, id)
+            }
+
+            #[ink(message)]
+            fn attribute_keys(&self, id: Id) -> Vec<String> {
+                AFT37MetadataImpl::attribute_keys(self, id)
+            }
         }
     ))
     .expect("Should parse");
@@ -1601,6 +2215,24 @@ pub(crate) fn impl_aft37_mintable(impl_args: &mut ImplArgs) {
 
 pub(crate) fn impl_aft37_enumerable(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::Internal for #storage_struct_name {
+            fn _max_page_size(&self) -> u128 {
+                enumerable::InternalImpl::_max_page_size(self)
+            }
+
+            fn _holders_page(&self, id: &Id, offset: u128, limit: u128) -> Vec<AccountId> {
+                enumerable::InternalImpl::_holders_page(self, id, offset, limit)
+            }
+        }
+    ))
+    .expect("Should parse");
+
     let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl AFT37EnumerableImpl for #storage_struct_name {}
     ))
@@ -1617,6 +2249,21 @@ pub(crate) fn impl_aft37_enumerable(impl_args: &mut ImplArgs) {
             fn token_by_index(&self, index: u128) -> Option<Id> {
                 AFT37EnumerableImpl::token_by_index(self, index)
             }
+
+            #[ink(message)]
+            fn holder_by_index(&self, id: Id, index: u128) -> Option<AccountId> {
+                AFT37EnumerableImpl::holder_by_index(self, id, index)
+            }
+
+            #[ink(message)]
+            fn holder_count(&self, id: Id) -> u128 {
+                AFT37EnumerableImpl::holder_count(self, id)
+            }
+
+            #[ink(message)]
+            fn holders_of(&self, id: Id, offset: u128, limit: u128) -> Vec<AccountId> {
+                AFT37EnumerableImpl::holders_of(self, id, offset, limit)
+            }
         }
     ))
     .expect("Should parse");
@@ -1662,11 +2309,17 @@ pub(crate) fn impl_aft37_enumerable(impl_args: &mut ImplArgs) {
                 operator: &AccountId,
                 id: &Option<&Id>,
                 amount: &Balance,
+                expires: &Option<Timestamp>,
             ) {
-                enumerable::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id, amount)
+                enumerable::BalancesManagerImpl::_insert_operator_approvals(self, owner, operator, id, amount, expires)
             }
 
-            fn _get_operator_approvals(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>) -> Option<Balance> {
+            fn _get_operator_approvals(
+                &self,
+                owner: &AccountId,
+                operator: &AccountId,
+                id: &Option<&Id>,
+            ) -> Option<(Balance, Option<Timestamp>)> {
                 enumerable::BalancesManagerImpl::_get_operator_approvals(self, owner, operator, id)
             }
 
@@ -1685,6 +2338,7 @@ pub(crate) fn impl_aft37_enumerable(impl_args: &mut ImplArgs) {
     impl_args.vec_import();
 
     override_functions("aft37::BalancesManager", &mut aft37_balances, impl_args.map);
+    override_functions("enumerable::Internal", &mut internal, impl_args.map);
     override_functions("AFT37Enumerable", &mut aft37_enumerable, impl_args.map);
 
     impl_args
@@ -1695,6 +2349,8 @@ pub(crate) fn impl_aft37_enumerable(impl_args: &mut ImplArgs) {
         syn::Item::Impl(aft37_balances_impl),
     );
 
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(enumerable_impl));
     impl_args.items.push(syn::Item::Impl(aft37_enumerable));
 }
@@ -1716,6 +2372,10 @@ pub(crate) fn impl_aft37_uri_storage(impl_args: &mut ImplArgs) {
                 uri_storage::InternalImpl::_emit_attribute_set_base_event(self, base_uri)
             }
 
+            fn _render_id(&self, token_id: &Id) -> String {
+                uri_storage::InternalImpl::_render_id(self, token_id)
+            }
+
             fn _set_token_uri(&mut self, token_id: Id, token_uri: URI) -> Result<(), AFT37Error> {
                 uri_storage::InternalImpl::_set_token_uri(self, token_id, token_uri)
             }
@@ -1766,3 +2426,608 @@ pub(crate) fn impl_aft37_uri_storage(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(uri_storage_impl));
     impl_args.items.push(syn::Item::Impl(uri_storage));
 }
+
+pub(crate) fn impl_aft37_royalty(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl royalty::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl royalty::Internal for #storage_struct_name {
+            fn _emit_royalty_set_event(&self, id: Option<Id>, royalty: Option<RoyaltyInfo>) {
+                royalty::InternalImpl::_emit_royalty_set_event(self, id, royalty)
+            }
+
+            fn _emit_royalty_split_set_event(&self, id: Option<Id>, shares: Vec<(AccountId, u16)>) {
+                royalty::InternalImpl::_emit_royalty_split_set_event(self, id, shares)
+            }
+
+            fn _fee_denominator(&self) -> u16 {
+                royalty::InternalImpl::_fee_denominator(self)
+            }
+
+            fn _royalty(&self, id: &Id) -> Option<RoyaltyInfo> {
+                royalty::InternalImpl::_royalty(self, id)
+            }
+
+            fn _royalty_split(&self, id: &Id) -> Option<Vec<(AccountId, u16)>> {
+                royalty::InternalImpl::_royalty_split(self, id)
+            }
+
+            fn _royalty_amount(&self, amount: Balance, fraction: u16) -> Balance {
+                royalty::InternalImpl::_royalty_amount(self, amount, fraction)
+            }
+
+            fn _set_default_royalty(&mut self, receiver: AccountId, royalty_fraction: u16) -> Result<(), AFT37Error> {
+                royalty::InternalImpl::_set_default_royalty(self, receiver, royalty_fraction)
+            }
+
+            fn _delete_default_royalty(&mut self) {
+                royalty::InternalImpl::_delete_default_royalty(self)
+            }
+
+            fn _set_token_royalty(&mut self, id: Id, receiver: AccountId, royalty_fraction: u16) -> Result<(), AFT37Error> {
+                royalty::InternalImpl::_set_token_royalty(self, id, receiver, royalty_fraction)
+            }
+
+            fn _reset_token_royalty(&mut self, id: Id) {
+                royalty::InternalImpl::_reset_token_royalty(self, id)
+            }
+
+            fn _set_royalty_split(&mut self, id: Option<Id>, shares: Vec<(AccountId, u16)>) -> Result<(), AFT37Error> {
+                royalty::InternalImpl::_set_royalty_split(self, id, shares)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let royalty_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT37RoyaltyImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut royalty = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT37Royalty for #storage_struct_name {
+            #[ink(message)]
+            fn royalty_info(&self, id: Id, sale_price: Balance) -> Option<(AccountId, Balance)> {
+                AFT37RoyaltyImpl::royalty_info(self, id, sale_price)
+            }
+
+            #[ink(message)]
+            fn royalty_split(&self, id: Id, sale_price: Balance) -> Vec<(AccountId, Balance)> {
+                AFT37RoyaltyImpl::royalty_split(self, id, sale_price)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft37::extensions::royalty::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT37Royalty", import);
+    impl_args.vec_import();
+
+    override_functions("royalty::Internal", &mut internal, impl_args.map);
+    override_functions("AFT37Royalty", &mut royalty, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(royalty_impl));
+    impl_args.items.push(syn::Item::Impl(royalty));
+}
+
+pub(crate) fn impl_aft34_lazy_mint(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl lazy_mint::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl lazy_mint::Internal for #storage_struct_name {
+            fn _emit_voucher_redeemed_event(&self, minter: AccountId, to: AccountId, id: Id, nonce: u64) {
+                lazy_mint::InternalImpl::_emit_voucher_redeemed_event(self, minter, to, id, nonce)
+            }
+
+            fn _redeem(&mut self, to: AccountId, voucher: Voucher, signature: [u8; 65]) -> Result<(), AFT34Error> {
+                lazy_mint::InternalImpl::_redeem(self, to, voucher, signature)
+            }
+
+            fn _recover_minter(&self, voucher: &Voucher, signature: &[u8; 65]) -> Result<AccountId, AFT34Error> {
+                lazy_mint::InternalImpl::_recover_minter(self, voucher, signature)
+            }
+
+            fn _collect_voucher_payment(&mut self, minter: AccountId, price: Balance) -> Result<(), AFT34Error> {
+                lazy_mint::InternalImpl::_collect_voucher_payment(self, minter, price)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let lazy_mint_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34LazyMintImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut lazy_mint = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34LazyMint for #storage_struct_name {
+            #[ink(message, payable)]
+            fn redeem(&mut self, to: AccountId, voucher: Voucher, signature: [u8; 65]) -> Result<(), AFT34Error> {
+                AFT34LazyMintImpl::redeem(self, to, voucher, signature)
+            }
+
+            #[ink(message)]
+            fn voucher_hash(&self, voucher: Voucher) -> [u8; 32] {
+                AFT34LazyMintImpl::voucher_hash(self, voucher)
+            }
+
+            #[ink(message)]
+            fn is_nonce_used(&self, minter: AccountId, nonce: u64) -> bool {
+                AFT34LazyMintImpl::is_nonce_used(self, minter, nonce)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft34::extensions::lazy_mint::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT34LazyMint", import);
+    impl_args.vec_import();
+
+    override_functions("lazy_mint::Internal", &mut internal, impl_args.map);
+    override_functions("AFT34LazyMint", &mut lazy_mint, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(lazy_mint_impl));
+    impl_args.items.push(syn::Item::Impl(lazy_mint));
+}
+
+pub(crate) fn impl_aft34_onchain_metadata(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl onchain_metadata::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl onchain_metadata::Internal for #storage_struct_name {
+            fn _metadata_json(&self, id: &Id) -> String {
+                onchain_metadata::InternalImpl::_metadata_json(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let onchain_metadata_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34OnchainMetadataImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut onchain_metadata = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34OnchainMetadata for #storage_struct_name {
+            #[ink(message)]
+            fn token_uri(&self, id: Id) -> Result<String, AFT34Error> {
+                AFT34OnchainMetadataImpl::token_uri(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft34::extensions::onchain_metadata::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT34OnchainMetadata", import);
+    impl_args.vec_import();
+
+    override_functions("onchain_metadata::Internal", &mut internal, impl_args.map);
+    override_functions("AFT34OnchainMetadata", &mut onchain_metadata, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(onchain_metadata_impl));
+    impl_args.items.push(syn::Item::Impl(onchain_metadata));
+}
+
+pub(crate) fn impl_aft34_rentable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl rentable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl rentable::Internal for #storage_struct_name {
+            fn _emit_update_user_event(&self, id: Id, user: Option<AccountId>, expires: Timestamp) {
+                rentable::InternalImpl::_emit_update_user_event(self, id, user, expires)
+            }
+
+            fn _set_user(&mut self, id: Id, user: Option<AccountId>, expires: Timestamp) -> Result<(), AFT34Error> {
+                rentable::InternalImpl::_set_user(self, id, user, expires)
+            }
+
+            fn _user_of(&self, id: &Id) -> Option<AccountId> {
+                rentable::InternalImpl::_user_of(self, id)
+            }
+
+            fn _clear_user(&mut self, id: &Id) {
+                rentable::InternalImpl::_clear_user(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let transfer_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl rentable::AFT34TransferImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let rentable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34RentableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut rentable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34Rentable for #storage_struct_name {
+            #[ink(message)]
+            fn set_user(&mut self, id: Id, user: Option<AccountId>, expires: Timestamp) -> Result<(), AFT34Error> {
+                AFT34RentableImpl::set_user(self, id, user, expires)
+            }
+
+            #[ink(message)]
+            fn user_of(&self, id: Id) -> Option<AccountId> {
+                AFT34RentableImpl::user_of(self, id)
+            }
+
+            #[ink(message)]
+            fn user_expires(&self, id: Id) -> Option<Timestamp> {
+                AFT34RentableImpl::user_expires(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft34::extensions::rentable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT34Rentable", import);
+    impl_args.vec_import();
+
+    override_functions("rentable::Internal", &mut internal, impl_args.map);
+    override_functions("AFT34Rentable", &mut rentable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(transfer_impl));
+    impl_args.items.push(syn::Item::Impl(rentable_impl));
+    impl_args.items.push(syn::Item::Impl(rentable));
+}
+
+pub(crate) fn impl_aft34_reveal(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl reveal::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl reveal::Internal for #storage_struct_name {
+            fn _emit_reveal_event(&self, seed: [u8; 32], starting_offset: u64) {
+                reveal::InternalImpl::_emit_reveal_event(self, seed, starting_offset)
+            }
+
+            fn _set_provenance(
+                &mut self,
+                provenance: [u8; 32],
+                seed_commitment: [u8; 32],
+            ) -> Result<(), AFT34Error> {
+                reveal::InternalImpl::_set_provenance(self, provenance, seed_commitment)
+            }
+
+            fn _set_placeholder_uri(&mut self, placeholder_uri: Option<String>) {
+                reveal::InternalImpl::_set_placeholder_uri(self, placeholder_uri)
+            }
+
+            fn _set_base_uri(&mut self, base_uri: Option<String>) {
+                reveal::InternalImpl::_set_base_uri(self, base_uri)
+            }
+
+            fn _set_collection_size(&mut self, collection_size: u64) -> Result<(), AFT34Error> {
+                reveal::InternalImpl::_set_collection_size(self, collection_size)
+            }
+
+            fn _reveal(&mut self, seed: [u8; 32]) -> Result<(), AFT34Error> {
+                reveal::InternalImpl::_reveal(self, seed)
+            }
+
+            fn _derive_starting_offset(&self, seed: &[u8; 32]) -> u64 {
+                reveal::InternalImpl::_derive_starting_offset(self, seed)
+            }
+
+            fn _metadata_id(&self, id: &Id) -> Result<Option<u64>, AFT34Error> {
+                reveal::InternalImpl::_metadata_id(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let reveal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34RevealImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut reveal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34Reveal for #storage_struct_name {
+            #[ink(message)]
+            fn reveal(&mut self, seed: [u8; 32]) -> Result<(), AFT34Error> {
+                AFT34RevealImpl::reveal(self, seed)
+            }
+
+            #[ink(message)]
+            fn set_provenance(
+                &mut self,
+                provenance: [u8; 32],
+                seed_commitment: [u8; 32],
+            ) -> Result<(), AFT34Error> {
+                AFT34RevealImpl::set_provenance(self, provenance, seed_commitment)
+            }
+
+            #[ink(message)]
+            fn token_uri(&self, id: Id) -> Result<Option<String>, AFT34Error> {
+                AFT34RevealImpl::token_uri(self, id)
+            }
+
+            #[ink(message)]
+            fn is_revealed(&self) -> bool {
+                AFT34RevealImpl::is_revealed(self)
+            }
+
+            #[ink(message)]
+            fn provenance(&self) -> Option<[u8; 32]> {
+                AFT34RevealImpl::provenance(self)
+            }
+
+            #[ink(message)]
+            fn seed_commitment(&self) -> Option<[u8; 32]> {
+                AFT34RevealImpl::seed_commitment(self)
+            }
+
+            #[ink(message)]
+            fn starting_offset(&self) -> Option<u64> {
+                AFT34RevealImpl::starting_offset(self)
+            }
+
+            #[ink(message)]
+            fn metadata_id(&self, id: Id) -> Result<Option<u64>, AFT34Error> {
+                AFT34RevealImpl::metadata_id(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft34::extensions::reveal::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT34Reveal", import);
+    impl_args.vec_import();
+
+    override_functions("reveal::Internal", &mut internal, impl_args.map);
+    override_functions("AFT34Reveal", &mut reveal, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(reveal_impl));
+    impl_args.items.push(syn::Item::Impl(reveal));
+}
+
+pub(crate) fn impl_aft34_soulbound(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl soulbound::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl soulbound::Internal for #storage_struct_name {
+            fn _emit_locked_event(&self, id: Id) {
+                soulbound::InternalImpl::_emit_locked_event(self, id)
+            }
+
+            fn _emit_unlocked_event(&self, id: Id) {
+                soulbound::InternalImpl::_emit_unlocked_event(self, id)
+            }
+
+            fn _is_locked(&self, id: &Id) -> bool {
+                soulbound::InternalImpl::_is_locked(self, id)
+            }
+
+            fn _set_locked_by_default(&mut self, locked: bool) {
+                soulbound::InternalImpl::_set_locked_by_default(self, locked)
+            }
+
+            fn _lock(&mut self, id: Id) -> Result<(), AFT34Error> {
+                soulbound::InternalImpl::_lock(self, id)
+            }
+
+            fn _unlock(&mut self, id: Id) -> Result<(), AFT34Error> {
+                soulbound::InternalImpl::_unlock(self, id)
+            }
+
+            fn _set_burn_allowed(&mut self, burn_allowed: bool) {
+                soulbound::InternalImpl::_set_burn_allowed(self, burn_allowed)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let transfer_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl soulbound::AFT34TransferImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let soulbound_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34SoulboundImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut soulbound = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34Soulbound for #storage_struct_name {
+            #[ink(message)]
+            fn locked(&self, id: Id) -> Result<bool, AFT34Error> {
+                AFT34SoulboundImpl::locked(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft34::extensions::soulbound::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT34Soulbound", import);
+    impl_args.vec_import();
+
+    override_functions("soulbound::Internal", &mut internal, impl_args.map);
+    override_functions("AFT34Soulbound", &mut soulbound, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(transfer_impl));
+    impl_args.items.push(syn::Item::Impl(soulbound_impl));
+    impl_args.items.push(syn::Item::Impl(soulbound));
+}
+
+pub(crate) fn impl_aft34_nestable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl nestable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl nestable::Internal for #storage_struct_name {
+            fn _emit_child_added_event(&self, parent_id: Id, child: Child, pending: bool) {
+                nestable::InternalImpl::_emit_child_added_event(self, parent_id, child, pending)
+            }
+
+            fn _emit_child_accepted_event(&self, parent_id: Id, child: Child) {
+                nestable::InternalImpl::_emit_child_accepted_event(self, parent_id, child)
+            }
+
+            fn _emit_child_transferred_event(&self, parent_id: Id, child: Child, to: AccountId) {
+                nestable::InternalImpl::_emit_child_transferred_event(self, parent_id, child, to)
+            }
+
+            fn _root_of(&self, id: &Id) -> Option<(Id, AccountId)> {
+                nestable::InternalImpl::_root_of(self, id)
+            }
+
+            fn _check_parent_access(&self, parent_id: &Id) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_check_parent_access(self, parent_id)
+            }
+
+            fn _check_nesting_cycle(&self, parent_id: &Id, id: &Id) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_check_nesting_cycle(self, parent_id, id)
+            }
+
+            fn _receive_child(&mut self, parent_id: &Id, child: &Child) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_receive_child(self, parent_id, child)
+            }
+
+            fn _send_child(&mut self, child: &Child, to: AccountId) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_send_child(self, child, to)
+            }
+
+            fn _insert_child(&mut self, parent_id: Id, child: Child, accepted: bool) {
+                nestable::InternalImpl::_insert_child(self, parent_id, child, accepted)
+            }
+
+            fn _accept_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_accept_child(self, parent_id, child)
+            }
+
+            fn _remove_child(&mut self, parent_id: &Id, child: &Child) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_remove_child(self, parent_id, child)
+            }
+
+            fn _release_token(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error> {
+                nestable::InternalImpl::_release_token(self, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let transfer_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl nestable::AFT34TransferImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let nestable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34NestableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut nestable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AFT34Nestable for #storage_struct_name {
+            #[ink(message)]
+            fn add_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error> {
+                AFT34NestableImpl::add_child(self, parent_id, child)
+            }
+
+            #[ink(message)]
+            fn accept_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error> {
+                AFT34NestableImpl::accept_child(self, parent_id, child)
+            }
+
+            #[ink(message)]
+            fn transfer_child(&mut self, parent_id: Id, child: Child, to: AccountId) -> Result<(), AFT34Error> {
+                AFT34NestableImpl::transfer_child(self, parent_id, child, to)
+            }
+
+            #[ink(message)]
+            fn children(&self, parent_id: Id) -> Vec<Child> {
+                AFT34NestableImpl::children(self, parent_id)
+            }
+
+            #[ink(message)]
+            fn pending_children(&self, parent_id: Id) -> Vec<Child> {
+                AFT34NestableImpl::pending_children(self, parent_id)
+            }
+
+            #[ink(message)]
+            fn parent_of(&self, id: Id) -> Option<Id> {
+                AFT34NestableImpl::parent_of(self, id)
+            }
+
+            #[ink(message)]
+            fn root_owner_of(&self, id: Id) -> Option<AccountId> {
+                AFT34NestableImpl::root_owner_of(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use allfeat_contracts::aft34::extensions::nestable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AFT34Nestable", import);
+    impl_args.vec_import();
+
+    override_functions("nestable::Internal", &mut internal, impl_args.map);
+    override_functions("AFT34Nestable", &mut nestable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(transfer_impl));
+    impl_args.items.push(syn::Item::Impl(nestable_impl));
+    impl_args.items.push(syn::Item::Impl(nestable));
+}
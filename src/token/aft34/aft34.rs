@@ -26,18 +26,33 @@ pub use crate::{
 };
 pub use aft34::{AFT34Impl as _, BalancesManager as _, Internal as _, InternalImpl as _};
 use ink::prelude::vec::Vec;
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
 use openbrush::storage::{Mapping, TypeGuard};
 use openbrush::traits::DefaultEnv;
-use openbrush::traits::{AccountId, Balance, Storage};
+use openbrush::traits::{AccountId, Balance, Storage, String, Timestamp};
 
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
     pub token_owner: Mapping<Id, Owner>,
+    /// Approvals written before expiries existed, for all tokens or a single token, read as never expiring.
     pub operator_approvals: Mapping<(Owner, Operator, Option<Id>), (), ApprovalsKey>,
+    /// Approvals for all tokens of an owner, with an optional expiry.
+    pub operator_approvals_with_expiry:
+        Mapping<(Owner, Operator, Option<Id>), Option<Timestamp>, ApprovalsKey>,
+    /// Account approved for each token with an optional expiry, cleared whenever the token moves.
+    pub token_approvals_with_expiry: Mapping<Id, (Operator, Option<Timestamp>)>,
     pub owned_tokens_count: Mapping<Owner, u32>,
     #[lazy]
     pub total_supply: Balance,
+    /// Consecutive batches of each id variant, sorted by their first id.
+    pub consecutive_batches: Mapping<(IdKind, u32), ConsecutiveBatch>,
+    pub consecutive_batches_count: Mapping<IdKind, u32>,
+    /// Tokens of consecutive batches that were burned, their owner being resolved from the batch otherwise.
+    pub consecutive_burned: Mapping<Id, ()>,
+    /// Highest `Id::U64` and `Id::U128` given an owner of their own, consecutive batches having to start above it.
+    pub highest_owned: Mapping<IdKind, u128>,
 }
 
 pub struct ApprovalsKey;
@@ -46,6 +61,37 @@ impl<'a> TypeGuard<'a> for ApprovalsKey {
     type Type = &'a (&'a Owner, &'a Operator, &'a Option<&'a Id>);
 }
 
+/// Range of sequential ids minted at once to `owner`, from `first` to `last` inclusive.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct ConsecutiveBatch {
+    pub first: u128,
+    pub last: u128,
+    pub owner: AccountId,
+    /// Whether the ids of the batch are `Id::U128`, `Id::U64` otherwise.
+    pub u128_ids: bool,
+}
+
+impl ConsecutiveBatch {
+    /// Returns the id of the batch at position `index`.
+    pub fn id(&self, index: u128) -> Id {
+        if self.u128_ids {
+            Id::U128(index)
+        } else {
+            Id::U64(index as u64)
+        }
+    }
+
+    /// Returns `true` if `id` is part of the batch.
+    pub fn contains(&self, id: &Id) -> bool {
+        match id {
+            Id::U64(v) => !self.u128_ids && (self.first..=self.last).contains(&(*v as u128)),
+            Id::U128(v) => self.u128_ids && (self.first..=self.last).contains(v),
+            _ => false,
+        }
+    }
+}
+
 /// Extend the PSP34Impl.
 /// This way, the AFT34 can override default PSP34 API and add new functions.
 pub trait AFT34Impl: Internal + AFT34 + BalancesManager + Sized {
@@ -66,13 +112,36 @@ pub trait AFT34Impl: Internal + AFT34 + BalancesManager + Sized {
         self._allowance(&owner, &operator, &id.as_ref())
     }
 
+    fn approval_expiry(
+        &self,
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+    ) -> Option<Option<Timestamp>> {
+        self._approval(&owner, &operator, &id.as_ref())
+    }
+
+    fn get_approved(&self, id: Id) -> Option<AccountId> {
+        let (operator, expires) = self._token_approval(&id)?;
+        is_live(&expires, Self::env().block_timestamp()).then_some(operator)
+    }
+
     fn approve(
         &mut self,
         operator: AccountId,
         id: Option<Id>,
         approved: bool,
     ) -> Result<(), AFT34Error> {
-        self._approve_for(operator, id, approved)
+        self._approve_for(operator, id, approved, None)
+    }
+
+    fn approve_until(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        expires: Timestamp,
+    ) -> Result<(), AFT34Error> {
+        self._approve_for(operator, id, true, Some(expires))
     }
 
     fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), AFT34Error> {
@@ -88,14 +157,24 @@ pub trait Internal {
     /// Those methods must be implemented in derived implementation
     fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id);
 
-    fn _emit_approval_event(&self, from: AccountId, to: AccountId, id: Option<Id>, approved: bool);
+    fn _emit_approval_event(
+        &self,
+        from: AccountId,
+        to: AccountId,
+        id: Option<Id>,
+        approved: bool,
+        expires: Option<Timestamp>,
+    );
 
     /// Approve the passed AccountId to transfer the specified token on behalf of the message's sender.
+    ///
+    /// The approval lapses at `expires` if it is set.
     fn _approve_for(
         &mut self,
         to: AccountId,
         id: Option<Id>,
         approved: bool,
+        expires: Option<Timestamp>,
     ) -> Result<(), AFT34Error>;
 
     /// Returns the owner of the token.
@@ -104,12 +183,68 @@ pub trait Internal {
     /// Gets an operator on other Account's behalf.
     fn _transfer_token(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), AFT34Error>;
 
-    fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error>;
+    /// Returns the rules the ids minted in the collection must follow, any id being accepted by default.
+    ///
+    /// Override it with `#[overrider(aft34::Internal)]` to restrict the ids of the collection.
+    ///
+    /// Ids are only normalized when minted: the other entry points look tokens up by the id
+    /// returned by `_mint_to` and emitted in the `Transfer` event.
+    fn _id_policy(&self) -> IdPolicy;
+
+    /// Mints the token `id` to `to` and returns the id it was minted under.
+    ///
+    /// The id is normalized by the id policy first, so the token may be minted under another variant.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidId` error if `id` doesn't follow the id policy.
+    ///
+    /// Returns `TokenExists` error if `id` is already minted.
+    fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<Id, AFT34Error>;
+
+    /// Mints `amount` sequential tokens to `to` starting at `first_id`, recording a single batch
+    /// as described in [EIP-2309](https://eips.ethereum.org/EIPS/eip-2309).
+    ///
+    /// The owners are resolved lazily from the batch, and the per-token transfer events are replaced by
+    /// `_emit_consecutive_transfer_event`. The transfer hooks still run for each token of the batch,
+    /// so contracts doing work in them should bound `BalancesManager::_max_consecutive_batch_size`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("UnsupportedId")` error if `first_id` is neither an `Id::U64` nor an `Id::U128`.
+    ///
+    /// Returns `Custom("BatchTooLarge")` error if `amount` exceeds the maximum consecutive batch size.
+    ///
+    /// Returns `Custom("InvalidIdRange")` error if the range overflows its id type or doesn't start
+    /// after the previous batch of its id type.
+    ///
+    /// Returns `TokenExists` error if the range doesn't start after the ids of its type minted through `_mint_to`.
+    ///
+    /// Returns `InvalidId` error if the first or the last id doesn't follow the id policy. The ids of a batch share
+    /// its variant, so they are checked but never normalized.
+    fn _mint_consecutive(
+        &mut self,
+        to: AccountId,
+        first_id: Id,
+        amount: u128,
+    ) -> Result<(), AFT34Error>;
+
+    /// Event is emitted when the tokens `from_id` to `to_id` are minted at once to `to`.
+    fn _emit_consecutive_transfer_event(&self, from_id: Id, to_id: Id, to: AccountId);
 
     fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), AFT34Error>;
 
     fn _allowance(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> bool;
 
+    /// Returns the expiry of the live approval of `operator` for `id`,
+    /// or `None` if the operator is not approved.
+    fn _approval(
+        &self,
+        owner: &Owner,
+        operator: &Operator,
+        id: &Option<&Id>,
+    ) -> Option<Option<Timestamp>>;
+
     fn _check_token_exists(&self, id: &Id) -> Result<AccountId, AFT34Error>;
 
     fn _before_token_transfer(
@@ -136,6 +271,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         _to: AccountId,
         _id: Option<Id>,
         _approved: bool,
+        _expires: Option<Timestamp>,
     ) {
     }
 
@@ -144,28 +280,46 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         to: AccountId,
         id: Option<Id>,
         approved: bool,
+        expires: Option<Timestamp>,
     ) -> Result<(), AFT34Error> {
         let mut caller = Self::env().caller();
 
-        if let Some(id) = &id {
-            let owner = Internal::_owner_of(self, id).ok_or(AFT34Error::TokenNotExists)?;
-
-            if approved && owner == to {
-                return Err(AFT34Error::SelfApprove);
-            }
-
-            if owner != caller && !Internal::_allowance(self, &owner, &caller, &None) {
-                return Err(AFT34Error::NotApproved);
-            };
-            caller = owner;
+        if approved && !is_live(&expires, Self::env().block_timestamp()) {
+            return Err(AFT34Error::Custom(String::from("InvalidExpiry")));
         }
 
-        if approved {
-            self._insert_operator_approvals(&caller, &to, &id.as_ref());
-        } else {
-            self._remove_operator_approvals(&caller, &to, &id.as_ref());
+        match &id {
+            // A token has at most one approved account, replaced by each approval.
+            Some(id) => {
+                let owner = Internal::_owner_of(self, id).ok_or(AFT34Error::TokenNotExists)?;
+
+                if approved && owner == to {
+                    return Err(AFT34Error::SelfApprove);
+                }
+
+                if owner != caller && !Internal::_allowance(self, &owner, &caller, &None) {
+                    return Err(AFT34Error::NotApproved);
+                };
+                caller = owner;
+
+                if approved {
+                    self._insert_token_approval(id, &to, &expires);
+                } else {
+                    self._remove_operator_approvals(&owner, &to, &Some(id));
+                    if self._token_approval(id).map(|(operator, _)| operator) == Some(to) {
+                        self._remove_token_approval(id);
+                    }
+                }
+            }
+            None => {
+                if approved {
+                    self._insert_operator_approvals(&caller, &to, &None, &expires);
+                } else {
+                    self._remove_operator_approvals(&caller, &to, &None);
+                }
+            }
         }
-        Internal::_emit_approval_event(self, caller, to, id, approved);
+        Internal::_emit_approval_event(self, caller, to, id, approved, expires);
 
         Ok(())
     }
@@ -184,6 +338,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
 
         Internal::_before_token_transfer(self, Some(&owner), Some(&to), &id)?;
 
+        self._remove_token_approval(&id);
         self._remove_operator_approvals(&owner, &caller, &Some(&id));
         BalancesManager::_decrease_balance(self, &owner, &id, false);
         self._remove_token_owner(&id);
@@ -196,7 +351,12 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         Ok(())
     }
 
-    fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error> {
+    fn _id_policy(&self) -> IdPolicy {
+        IdPolicy::default()
+    }
+
+    fn _mint_to(&mut self, to: AccountId, id: Id) -> Result<Id, AFT34Error> {
+        let id = Internal::_id_policy(self).normalize(id)?;
         if Internal::_owner_of(self, &id).is_some() {
             return Err(AFT34Error::TokenExists);
         }
@@ -205,16 +365,64 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         BalancesManager::_increase_balance(self, &to, &id, true);
         self._insert_token_owner(&id, &to);
         Internal::_after_token_transfer(self, None, Some(&to), &id)?;
-        Internal::_emit_transfer_event(self, None, Some(to), id);
+        Internal::_emit_transfer_event(self, None, Some(to), id.clone());
+
+        Ok(id)
+    }
+
+    fn _mint_consecutive(
+        &mut self,
+        to: AccountId,
+        first_id: Id,
+        amount: u128,
+    ) -> Result<(), AFT34Error> {
+        if amount == 0 {
+            return Err(AFT34Error::Custom(String::from("CannotMintZeroTokens")));
+        }
+        if BalancesManager::_max_consecutive_batch_size(self).map_or(false, |max| amount > max) {
+            return Err(AFT34Error::Custom(String::from("BatchTooLarge")));
+        }
+        let (first, max, u128_ids) = match first_id {
+            Id::U64(v) => (v as u128, u64::MAX as u128, false),
+            Id::U128(v) => (v, u128::MAX, true),
+            _ => return Err(AFT34Error::Custom(String::from("UnsupportedId"))),
+        };
+        let last = first
+            .checked_add(amount - 1)
+            .filter(|last| *last <= max)
+            .ok_or_else(|| AFT34Error::Custom(String::from("InvalidIdRange")))?;
+
+        let batch = ConsecutiveBatch {
+            first,
+            last,
+            owner: to,
+            u128_ids,
+        };
+        let last_id = batch.id(last);
+        let policy = Internal::_id_policy(self);
+        policy.check(&first_id)?;
+        policy.check(&last_id)?;
+
+        for index in first..=last {
+            Internal::_before_token_transfer(self, None, Some(&to), &batch.id(index))?;
+        }
+        BalancesManager::_insert_consecutive_batch(self, batch.clone())?;
+        for index in first..=last {
+            Internal::_after_token_transfer(self, None, Some(&to), &batch.id(index))?;
+        }
+        Internal::_emit_consecutive_transfer_event(self, first_id, last_id, to);
 
         Ok(())
     }
 
+    fn _emit_consecutive_transfer_event(&self, _from_id: Id, _to_id: Id, _to: AccountId) {}
+
     fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), AFT34Error> {
         Internal::_check_token_exists(self, &id)?;
 
         Internal::_before_token_transfer(self, Some(&from), None, &id)?;
 
+        self._remove_token_approval(&id);
         self._remove_token_owner(&id);
         BalancesManager::_decrease_balance(self, &from, &id, true);
         Internal::_after_token_transfer(self, Some(&from), None, &id)?;
@@ -223,8 +431,36 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
     }
 
     fn _allowance(&self, owner: &Owner, operator: &Operator, id: &Option<&Id>) -> bool {
-        self._operator_approvals(owner, operator, &None).is_some()
-            || id.is_some() && self._operator_approvals(owner, operator, id).is_some()
+        Internal::_approval(self, owner, operator, id).is_some()
+    }
+
+    fn _approval(
+        &self,
+        owner: &Owner,
+        operator: &Operator,
+        id: &Option<&Id>,
+    ) -> Option<Option<Timestamp>> {
+        let now = Self::env().block_timestamp();
+
+        self._operator_approvals(owner, operator, &None)
+            .filter(|expires| is_live(expires, now))
+            .or_else(|| {
+                let id = (*id)?;
+                // Approvals for a single token written before `token_approvals_with_expiry` existed.
+                if self
+                    ._operator_approvals(owner, operator, &Some(id))
+                    .is_some()
+                {
+                    return Some(None);
+                }
+
+                let (approved, expires) = self._token_approval(id)?;
+
+                (approved == *operator
+                    && is_live(&expires, now)
+                    && Internal::_owner_of(self, id).as_ref() == Some(owner))
+                .then_some(expires)
+            })
     }
 
     fn _check_token_exists(&self, id: &Id) -> Result<AccountId, AFT34Error> {
@@ -266,15 +502,34 @@ pub trait BalancesManager {
         owner: &Owner,
         operator: &Operator,
         id: &Option<&Id>,
-    ) -> Option<()>;
+    ) -> Option<Option<Timestamp>>;
 
-    fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>);
+    fn _insert_operator_approvals(
+        &mut self,
+        owner: &Owner,
+        operator: &Operator,
+        id: &Option<&Id>,
+        expires: &Option<Timestamp>,
+    );
 
     fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>);
 
+    /// Returns the account approved for `id` and the approval expiry, if any.
+    fn _token_approval(&self, id: &Id) -> Option<(Operator, Option<Timestamp>)>;
+
+    fn _insert_token_approval(&mut self, id: &Id, operator: &Operator, expires: &Option<Timestamp>);
+
+    fn _remove_token_approval(&mut self, id: &Id);
+
     fn _insert_token_owner(&mut self, id: &Id, to: &AccountId);
 
     fn _remove_token_owner(&mut self, id: &Id);
+
+    /// Records `batch`, crediting its owner and the total supply with all its tokens.
+    fn _insert_consecutive_batch(&mut self, batch: ConsecutiveBatch) -> Result<(), AFT34Error>;
+
+    /// Maximum amount of tokens minted by a single consecutive batch, unbounded if `None`.
+    fn _max_consecutive_batch_size(&self) -> Option<u128>;
 }
 
 pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
@@ -310,7 +565,50 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
     }
 
     fn _owner_of(&self, id: &Id) -> Option<AccountId> {
-        self.data().token_owner.get(id)
+        self.data()
+            .token_owner
+            .get(id)
+            .or_else(|| self._consecutive_owner(id))
+    }
+
+    /// Returns the owner of `id` from the consecutive batch it is part of, unless it was burned.
+    fn _consecutive_owner(&self, id: &Id) -> Option<AccountId> {
+        if !matches!(id, Id::U64(_) | Id::U128(_)) || self.data().consecutive_burned.contains(id) {
+            return None;
+        }
+
+        self._consecutive_batch(id).map(|batch| batch.owner)
+    }
+
+    /// Returns the consecutive batch `id` is part of, using a binary search over the sorted batches
+    /// of its id variant.
+    fn _consecutive_batch(&self, id: &Id) -> Option<ConsecutiveBatch> {
+        let index = match id {
+            Id::U64(v) => *v as u128,
+            Id::U128(v) => *v,
+            _ => return None,
+        };
+        let kind = id.kind();
+
+        let (mut low, mut high) = (
+            0,
+            self.data()
+                .consecutive_batches_count
+                .get(&kind)
+                .unwrap_or(0),
+        );
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let batch = self.data().consecutive_batches.get(&(kind, middle))?;
+            if index < batch.first {
+                high = middle;
+            } else if index > batch.last {
+                low = middle + 1;
+            } else {
+                return batch.contains(id).then_some(batch);
+            }
+        }
+        None
     }
 
     fn _operator_approvals(
@@ -318,27 +616,133 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
         owner: &Owner,
         operator: &Operator,
         id: &Option<&Id>,
-    ) -> Option<()> {
-        self.data().operator_approvals.get(&(owner, operator, id))
+    ) -> Option<Option<Timestamp>> {
+        let key = (owner, operator, id);
+        self.data()
+            .operator_approvals_with_expiry
+            .get(&key)
+            .or_else(|| self.data().operator_approvals.get(&key).map(|_| None))
     }
 
-    fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
+    fn _insert_operator_approvals(
+        &mut self,
+        owner: &Owner,
+        operator: &Operator,
+        id: &Option<&Id>,
+        expires: &Option<Timestamp>,
+    ) {
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
         self.data()
-            .operator_approvals
-            .insert(&(owner, operator, id), &());
+            .operator_approvals_with_expiry
+            .insert(&key, expires);
     }
 
     fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
+        self.data().operator_approvals_with_expiry.remove(&key);
+    }
+
+    fn _token_approval(&self, id: &Id) -> Option<(Operator, Option<Timestamp>)> {
+        self.data().token_approvals_with_expiry.get(id)
+    }
+
+    fn _insert_token_approval(
+        &mut self,
+        id: &Id,
+        operator: &Operator,
+        expires: &Option<Timestamp>,
+    ) {
         self.data()
-            .operator_approvals
-            .remove(&(owner, operator, id));
+            .token_approvals_with_expiry
+            .insert(id, &(*operator, *expires));
+    }
+
+    fn _remove_token_approval(&mut self, id: &Id) {
+        self.data().token_approvals_with_expiry.remove(id);
     }
 
     fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
         self.data().token_owner.insert(id, to);
+        if self.data().consecutive_burned.contains(id) {
+            self.data().consecutive_burned.remove(id);
+        }
+
+        if let (Id::U64(_) | Id::U128(_), Some(value)) = (id, id.as_u128()) {
+            let kind = id.kind();
+            if self
+                .data()
+                .highest_owned
+                .get(&kind)
+                .map_or(true, |highest| value > highest)
+            {
+                self.data().highest_owned.insert(&kind, &value);
+            }
+        }
     }
 
     fn _remove_token_owner(&mut self, id: &Id) {
         self.data().token_owner.remove(id);
+        if self._consecutive_owner(id).is_some() {
+            self.data().consecutive_burned.insert(id, &());
+        }
+    }
+
+    fn _insert_consecutive_batch(&mut self, batch: ConsecutiveBatch) -> Result<(), AFT34Error> {
+        let kind = batch.id(batch.first).kind();
+        let count = self
+            .data()
+            .consecutive_batches_count
+            .get(&kind)
+            .unwrap_or(0);
+        if let Some(previous) = count
+            .checked_sub(1)
+            .and_then(|index| self.data().consecutive_batches.get(&(kind, index)))
+        {
+            if batch.first <= previous.last {
+                return Err(AFT34Error::Custom(String::from("InvalidIdRange")));
+            }
+        }
+
+        if self
+            .data()
+            .highest_owned
+            .get(&kind)
+            .map_or(false, |highest| batch.first <= highest)
+        {
+            return Err(AFT34Error::TokenExists);
+        }
+
+        let amount = batch.last - batch.first + 1;
+        let balance = self
+            .data()
+            .owned_tokens_count
+            .get(&batch.owner)
+            .unwrap_or(0) as u128;
+        let new_balance = u32::try_from(balance + amount)
+            .map_err(|_| AFT34Error::Custom(String::from("BalanceOverflow")))?;
+        self.data()
+            .owned_tokens_count
+            .insert(&batch.owner, &new_balance);
+        let new_supply = self.data().total_supply.get_or_default() + amount;
+        self.data().total_supply.set(&new_supply);
+
+        self.data()
+            .consecutive_batches
+            .insert(&(kind, count), &batch);
+        self.data()
+            .consecutive_batches_count
+            .insert(&kind, &(count + 1));
+        Ok(())
     }
+
+    fn _max_consecutive_batch_size(&self) -> Option<u128> {
+        None
+    }
+}
+
+/// Whether an approval with `expires` is still valid at `now`.
+fn is_live(expires: &Option<Timestamp>, now: Timestamp) -> bool {
+    expires.map_or(true, |expires| now < expires)
 }
@@ -19,7 +19,7 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use crate::aft34::ApprovalsKey;
+use crate::aft34::{ApprovalsKey, ConsecutiveBatch};
 pub use crate::{
     aft34,
     aft34::extensions::enumerable,
@@ -28,16 +28,22 @@ pub use crate::{
 pub use aft34::{
     AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
 };
+pub use enumerable::Internal as _;
+use ink::prelude::vec::Vec;
 use openbrush::{
     storage::{Mapping, MultiMapping, TypeGuard},
-    traits::{AccountId, Balance, Storage},
+    traits::{AccountId, Balance, Storage, Timestamp},
 };
 
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
     pub token_owner: Mapping<Id, Owner>,
+    /// Approvals written before expiries existed, for all tokens or a single token, read as never expiring.
     pub operator_approvals: Mapping<(Owner, Operator, Option<Id>), (), ApprovalsKey>,
+    pub operator_approvals_with_expiry:
+        Mapping<(Owner, Operator, Option<Id>), Option<Timestamp>, ApprovalsKey>,
+    pub token_approvals_with_expiry: Mapping<Id, (Operator, Option<Timestamp>)>,
     pub balances: MultiMapping<Option<AccountId>, Id, EnumerableKey>,
 }
 
@@ -79,20 +85,51 @@ pub trait BalancesManagerImpl: Storage<Data> {
         owner: &Owner,
         operator: &Operator,
         id: &Option<&Id>,
-    ) -> Option<()> {
-        self.data().operator_approvals.get(&(owner, operator, id))
+    ) -> Option<Option<Timestamp>> {
+        let key = (owner, operator, id);
+        self.data()
+            .operator_approvals_with_expiry
+            .get(&key)
+            .or_else(|| self.data().operator_approvals.get(&key).map(|_| None))
     }
 
-    fn _insert_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
+    fn _insert_operator_approvals(
+        &mut self,
+        owner: &Owner,
+        operator: &Operator,
+        id: &Option<&Id>,
+        expires: &Option<Timestamp>,
+    ) {
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
         self.data()
-            .operator_approvals
-            .insert(&(owner, operator, id), &());
+            .operator_approvals_with_expiry
+            .insert(&key, expires);
     }
 
     fn _remove_operator_approvals(&mut self, owner: &Owner, operator: &Operator, id: &Option<&Id>) {
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
+        self.data().operator_approvals_with_expiry.remove(&key);
+    }
+
+    fn _token_approval(&self, id: &Id) -> Option<(Operator, Option<Timestamp>)> {
+        self.data().token_approvals_with_expiry.get(id)
+    }
+
+    fn _insert_token_approval(
+        &mut self,
+        id: &Id,
+        operator: &Operator,
+        expires: &Option<Timestamp>,
+    ) {
         self.data()
-            .operator_approvals
-            .remove(&(owner, operator, id));
+            .token_approvals_with_expiry
+            .insert(id, &(*operator, *expires));
+    }
+
+    fn _remove_token_approval(&mut self, id: &Id) {
+        self.data().token_approvals_with_expiry.remove(id);
     }
 
     fn _insert_token_owner(&mut self, id: &Id, to: &AccountId) {
@@ -102,9 +139,28 @@ pub trait BalancesManagerImpl: Storage<Data> {
     fn _remove_token_owner(&mut self, id: &Id) {
         self.data().token_owner.remove(id);
     }
+
+    /// Enumerating the tokens requires indexing each of them, so the batch is recorded token by token.
+    fn _insert_consecutive_batch(&mut self, batch: ConsecutiveBatch) -> Result<(), AFT34Error> {
+        for index in batch.first..=batch.last {
+            let id = batch.id(index);
+            if self.data().token_owner.get(&id).is_some() {
+                return Err(AFT34Error::TokenExists);
+            }
+            self.data().balances.insert(&Some(&batch.owner), &id);
+            self.data().balances.insert(&None, &id);
+            self.data().token_owner.insert(&id, &batch.owner);
+        }
+        Ok(())
+    }
+
+    /// Batches are indexed token by token, so their size is bounded like a page of tokens.
+    fn _max_consecutive_batch_size(&self) -> Option<u128> {
+        Some(100)
+    }
 }
 
-pub trait AFT34EnumerableImpl: Storage<Data> {
+pub trait AFT34EnumerableImpl: Internal + Storage<Data> {
     fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Result<Id, AFT34Error> {
         self.data()
             .balances
@@ -118,4 +174,38 @@ pub trait AFT34EnumerableImpl: Storage<Data> {
             .get_value(&None, &index)
             .ok_or(AFT34Error::TokenNotExists)
     }
+
+    fn tokens_of_owner(&self, owner: AccountId, offset: u128, limit: u128) -> Vec<Id> {
+        Internal::_tokens_page(self, &Some(&owner), offset, limit)
+    }
+
+    fn tokens(&self, offset: u128, limit: u128) -> Vec<Id> {
+        Internal::_tokens_page(self, &None, offset, limit)
+    }
+}
+
+pub trait Internal {
+    /// Maximum amount of token ids returned by a single page query.
+    fn _max_page_size(&self) -> u128;
+
+    /// Returns up to `limit` token ids of `owner` starting at index `offset`.
+    /// If `owner` is `None`, pages through all the tokens of the contract.
+    fn _tokens_page(&self, owner: &Option<&AccountId>, offset: u128, limit: u128) -> Vec<Id>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _max_page_size(&self) -> u128 {
+        100
+    }
+
+    fn _tokens_page(&self, owner: &Option<&AccountId>, offset: u128, limit: u128) -> Vec<Id> {
+        let limit = limit.min(Internal::_max_page_size(self));
+        let end = offset
+            .saturating_add(limit)
+            .min(self.data().balances.count(owner));
+
+        (offset..end)
+            .filter_map(|index| self.data().balances.get_value(owner, &index))
+            .collect()
+    }
 }
@@ -0,0 +1,226 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft34,
+    aft34::extensions::{lazy_mint, uri_storage},
+    traits::aft34::{extensions::lazy_mint::*, *},
+};
+pub use aft34::{
+    AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
+};
+use ink::env::hash::{Blake2x256, Keccak256};
+pub use lazy_mint::Internal as _;
+use openbrush::{
+    contracts::access_control::{self, RoleType},
+    storage::{Mapping, TypeGuard},
+    traits::{AccountId, Balance, Storage, String},
+};
+
+/// Role of the accounts allowed to sign vouchers.
+pub const MINTER: RoleType = ink::selector_id!("MINTER");
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub used_nonces: Mapping<(AccountId, u64), (), UsedNonceKey>,
+}
+
+pub struct UsedNonceKey;
+
+impl<'a> TypeGuard<'a> for UsedNonceKey {
+    type Type = &'a (&'a AccountId, &'a u64);
+}
+
+/// Returns the hash of `voucher` signed by the minters, bound to the contract at `contract`.
+pub fn voucher_hash(contract: &AccountId, voucher: &Voucher) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    ink::env::hash_encoded::<Keccak256, _>(&(contract, voucher), &mut output);
+    output
+}
+
+pub trait AFT34LazyMintImpl: Internal + Storage<Data> {
+    fn redeem(
+        &mut self,
+        to: AccountId,
+        voucher: Voucher,
+        signature: [u8; 65],
+    ) -> Result<(), AFT34Error> {
+        Internal::_redeem(self, to, voucher, signature)
+    }
+
+    fn voucher_hash(&self, voucher: Voucher) -> [u8; 32] {
+        voucher_hash(&Self::env().account_id(), &voucher)
+    }
+
+    fn is_nonce_used(&self, minter: AccountId, nonce: u64) -> bool {
+        self.data::<Data>().used_nonces.contains(&(&minter, &nonce))
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when a voucher is redeemed.
+    fn _emit_voucher_redeemed_event(&self, minter: AccountId, to: AccountId, id: Id, nonce: u64);
+
+    fn _redeem(
+        &mut self,
+        to: AccountId,
+        voucher: Voucher,
+        signature: [u8; 65],
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the account that signed `voucher`.
+    fn _recover_minter(
+        &self,
+        voucher: &Voucher,
+        signature: &[u8; 65],
+    ) -> Result<AccountId, AFT34Error>;
+
+    /// Checks the transferred value against `price` and forwards it to `minter`.
+    fn _collect_voucher_payment(
+        &mut self,
+        minter: AccountId,
+        price: Balance,
+    ) -> Result<(), AFT34Error>;
+}
+
+pub trait InternalImpl:
+    Internal + Storage<Data> + aft34::Internal + uri_storage::Internal + access_control::Internal
+{
+    fn _emit_voucher_redeemed_event(
+        &self,
+        _minter: AccountId,
+        _to: AccountId,
+        _id: Id,
+        _nonce: u64,
+    ) {
+    }
+
+    fn _redeem(
+        &mut self,
+        to: AccountId,
+        voucher: Voucher,
+        signature: [u8; 65],
+    ) -> Result<(), AFT34Error> {
+        if Self::env().block_timestamp() > voucher.expiry {
+            return Err(AFT34Error::Custom(String::from("VoucherExpired")));
+        }
+        if voucher.recipient.map_or(false, |recipient| recipient != to) {
+            return Err(AFT34Error::Custom(String::from("InvalidRecipient")));
+        }
+
+        let minter = Internal::_recover_minter(self, &voucher, &signature)?;
+        access_control::Internal::_check_role(self, MINTER, Some(minter))?;
+
+        let nonce_key = (&minter, &voucher.nonce);
+        if self.data::<Data>().used_nonces.contains(&nonce_key) {
+            return Err(AFT34Error::Custom(String::from("VoucherAlreadyRedeemed")));
+        }
+        self.data::<Data>().used_nonces.insert(&nonce_key, &());
+
+        Internal::_collect_voucher_payment(self, minter, voucher.price)?;
+        // The URI is set on the id actually minted, which the id policy may have normalized.
+        let id = aft34::Internal::_mint_to(self, to, voucher.id)?;
+        uri_storage::Internal::_set_token_uri(self, id.clone(), voucher.uri)?;
+
+        Internal::_emit_voucher_redeemed_event(self, minter, to, id, voucher.nonce);
+        Ok(())
+    }
+
+    fn _recover_minter(
+        &self,
+        voucher: &Voucher,
+        signature: &[u8; 65],
+    ) -> Result<AccountId, AFT34Error> {
+        let hash = voucher_hash(&Self::env().account_id(), voucher);
+        let mut public_key = [0u8; 33];
+        ink::env::ecdsa_recover(signature, &hash, &mut public_key)
+            .map_err(|_| AFT34Error::Custom(String::from("InvalidSignature")))?;
+
+        // Substrate accounts of ECDSA keys are the hash of the compressed public key
+        let mut account = [0u8; 32];
+        ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+        Ok(AccountId::from(account))
+    }
+
+    fn _collect_voucher_payment(
+        &mut self,
+        minter: AccountId,
+        price: Balance,
+    ) -> Result<(), AFT34Error> {
+        if Self::env().transferred_value() != price {
+            return Err(AFT34Error::Custom(String::from("BadMintValue")));
+        }
+        if price > 0 {
+            Self::env()
+                .transfer(minter, price)
+                .map_err(|_| AFT34Error::Custom(String::from("PaymentFailed")))?;
+        }
+        Ok(())
+    }
+}
+
+/// Helpers to build the signatures of vouchers off-chain, e.g. in a marketplace backend or in tests.
+///
+/// Requires the `lazy-mint-signing` feature.
+#[cfg(feature = "lazy-mint-signing")]
+pub mod signing {
+    use super::{voucher_hash, AccountId, Voucher};
+    use ink::env::hash::{Blake2x256, CryptoHash, HashOutput};
+    use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+    /// Returns the account of the ECDSA key `secret_key`, the one to grant the [`super::MINTER`] role to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `secret_key` is not a valid secp256k1 secret key.
+    pub fn minter_account(secret_key: &[u8; 32]) -> AccountId {
+        let secret_key = SecretKey::from_slice(secret_key).expect("Invalid secret key");
+        let public_key = PublicKey::from_secret_key(&Secp256k1::signing_only(), &secret_key);
+
+        let mut account = <Blake2x256 as HashOutput>::Type::default();
+        Blake2x256::hash(&public_key.serialize(), &mut account);
+        AccountId::from(account)
+    }
+
+    /// Signs `voucher` with `secret_key` for the contract at `contract`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `secret_key` is not a valid secp256k1 secret key.
+    pub fn sign_voucher(
+        secret_key: &[u8; 32],
+        contract: &AccountId,
+        voucher: &Voucher,
+    ) -> [u8; 65] {
+        let secret_key = SecretKey::from_slice(secret_key).expect("Invalid secret key");
+        let message =
+            Message::from_slice(&voucher_hash(contract, voucher)).expect("Hash is 32 bytes");
+        let (recovery_id, compact) = Secp256k1::signing_only()
+            .sign_ecdsa_recoverable(&message, &secret_key)
+            .serialize_compact();
+
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        signature
+    }
+}
@@ -19,6 +19,7 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::aft34::all_ids;
 pub use crate::{
     aft34,
     aft34::extensions::metadata,
@@ -27,10 +28,11 @@ pub use crate::{
 pub use aft34::{
     AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
 };
+use ink::prelude::vec::Vec;
 pub use metadata::Internal as _;
 pub use openbrush::traits::String;
 use openbrush::{
-    storage::{Mapping, TypeGuard},
+    storage::{Mapping, MultiMapping, TypeGuard},
     traits::Storage,
 };
 
@@ -38,6 +40,9 @@ use openbrush::{
 #[openbrush::storage_item]
 pub struct Data {
     pub attributes: Mapping<(Id, String), String, AttributesKey>,
+    pub attribute_keys: MultiMapping<Id, String>,
+    pub frozen: Mapping<Id, ()>,
+    pub frozen_all: bool,
 }
 
 pub struct AttributesKey;
@@ -46,24 +51,142 @@ impl<'a> TypeGuard<'a> for AttributesKey {
     type Type = &'a (&'a Id, &'a String);
 }
 
-pub trait AFT34MetadataImpl: Storage<Data> {
+pub trait AFT34MetadataImpl: Internal + Storage<Data> {
     fn get_attribute(&self, id: Id, key: String) -> Option<String> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    fn get_attributes(&self, id: Id) -> Vec<(String, String)> {
+        Internal::_attribute_keys(self, &id)
+            .into_iter()
+            .filter_map(|key| {
+                self.data()
+                    .attributes
+                    .get(&(&id, &key))
+                    .map(|value| (key, value))
+            })
+            .collect()
+    }
+
+    fn attribute_keys(&self, id: Id) -> Vec<String> {
+        Internal::_attribute_keys(self, &id)
+    }
+
+    fn is_metadata_frozen(&self, id: Id) -> bool {
+        Internal::_metadata_frozen(self, &id)
+    }
 }
 
 pub trait Internal {
     /// Event is emitted when an attribute is set for a token.
     fn _emit_attribute_set_event(&self, id: Id, key: String, data: String);
+    /// Event is emitted when an attribute is removed from a token.
+    fn _emit_attribute_removed_event(&self, id: Id, key: String);
+    /// Event is emitted when the metadata of a token changes.
+    fn _emit_metadata_update_event(&self, id: Id);
+    /// Event is emitted when the metadata of a range of tokens changes.
+    fn _emit_batch_metadata_update_event(&self, from_id: Id, to_id: Id);
+    /// Event is emitted when the metadata of `id`, or of all ids if `None`, is frozen.
+    fn _emit_metadata_frozen_event(&self, id: Option<Id>);
+
+    /// Sets the attribute of `id` for the given `key`.
+    ///
+    /// Fails with `MetadataFrozen` if the metadata of `id` is frozen.
+    fn _set_attribute(&mut self, id: Id, key: String, value: String) -> Result<(), AFT34Error>;
 
-    fn _set_attribute(&mut self, id: Id, key: String, value: String);
+    /// Sets each `(key, value)` pair of `attributes` as an attribute of `id`.
+    ///
+    /// Fails with `MetadataFrozen` if the metadata of `id` is frozen.
+    fn _set_attributes(
+        &mut self,
+        id: Id,
+        attributes: Vec<(String, String)>,
+    ) -> Result<(), AFT34Error>;
+
+    /// Removes the attribute of `id` for the given `key`, if any.
+    ///
+    /// Fails with `MetadataFrozen` if the metadata of `id` is frozen.
+    fn _remove_attribute(&mut self, id: Id, key: String) -> Result<(), AFT34Error>;
+
+    /// Returns the keys of all the attributes set for `id`.
+    fn _attribute_keys(&self, id: &Id) -> Vec<String>;
+
+    /// Returns `true` if the metadata of `id` is frozen.
+    fn _metadata_frozen(&self, id: &Id) -> bool;
+
+    /// Permanently freezes the attributes of `id`.
+    fn _freeze_metadata(&mut self, id: Id);
+
+    /// Permanently freezes the attributes of every id, emitting a `BatchMetadataUpdate` event for all the ids.
+    fn _freeze_all(&mut self);
 }
 
 pub trait InternalImpl: Internal + Storage<Data> {
     fn _emit_attribute_set_event(&self, _id: Id, _key: String, _data: String) {}
+    fn _emit_attribute_removed_event(&self, _id: Id, _key: String) {}
+    fn _emit_metadata_update_event(&self, _id: Id) {}
+    fn _emit_batch_metadata_update_event(&self, _from_id: Id, _to_id: Id) {}
+    fn _emit_metadata_frozen_event(&self, _id: Option<Id>) {}
+
+    fn _set_attribute(&mut self, id: Id, key: String, value: String) -> Result<(), AFT34Error> {
+        if Internal::_metadata_frozen(self, &id) {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
+        if !self.data().attributes.contains(&(&id, &key)) {
+            self.data().attribute_keys.insert(&id, &key);
+        }
 
-    fn _set_attribute(&mut self, id: Id, key: String, value: String) {
         self.data().attributes.insert(&(&id, &key), &value);
-        Internal::_emit_attribute_set_event(self, id, key, value);
+        Internal::_emit_attribute_set_event(self, id.clone(), key, value);
+        Internal::_emit_metadata_update_event(self, id);
+        Ok(())
+    }
+
+    fn _set_attributes(
+        &mut self,
+        id: Id,
+        attributes: Vec<(String, String)>,
+    ) -> Result<(), AFT34Error> {
+        for (key, value) in attributes {
+            Internal::_set_attribute(self, id.clone(), key, value)?;
+        }
+        Ok(())
+    }
+
+    fn _remove_attribute(&mut self, id: Id, key: String) -> Result<(), AFT34Error> {
+        if Internal::_metadata_frozen(self, &id) {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
+        if self.data().attributes.contains(&(&id, &key)) {
+            self.data().attributes.remove(&(&id, &key));
+            self.data().attribute_keys.remove_value(&id, &key);
+            Internal::_emit_attribute_removed_event(self, id.clone(), key);
+            Internal::_emit_metadata_update_event(self, id);
+        }
+        Ok(())
+    }
+
+    fn _attribute_keys(&self, id: &Id) -> Vec<String> {
+        (0..self.data().attribute_keys.count(id))
+            .filter_map(|index| self.data().attribute_keys.get_value(id, &index))
+            .collect()
+    }
+
+    fn _metadata_frozen(&self, id: &Id) -> bool {
+        self.data().frozen_all || self.data().frozen.contains(id)
+    }
+
+    fn _freeze_metadata(&mut self, id: Id) {
+        self.data().frozen.insert(&id, &());
+        Internal::_emit_metadata_frozen_event(self, Some(id));
+    }
+
+    fn _freeze_all(&mut self) {
+        self.data().frozen_all = true;
+        Internal::_emit_metadata_frozen_event(self, None);
+        let (first, last) = all_ids();
+        Internal::_emit_batch_metadata_update_event(self, first, last);
     }
 }
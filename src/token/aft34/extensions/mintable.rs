@@ -30,6 +30,7 @@ use openbrush::traits::AccountId;
 
 pub trait AFT34MintableImpl: aft34::Internal {
     fn mint(&mut self, account: AccountId, id: Id) -> Result<(), AFT34Error> {
-        self._mint_to(account, id)
+        self._mint_to(account, id)?;
+        Ok(())
     }
 }
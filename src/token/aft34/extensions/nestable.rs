@@ -0,0 +1,398 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub use crate::{
+    aft34,
+    aft34::extensions::nestable,
+    traits::{
+        aft34::{extensions::nestable::*, *},
+        aft37::AFT37Ref,
+    },
+};
+pub use aft34::{
+    AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
+};
+pub use nestable::Internal as _;
+use ink::prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{AccountId, Storage, String},
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    /// Accepted children of each token.
+    pub children: Mapping<Id, Vec<Child>>,
+    /// Children of each token waiting to be accepted.
+    pub pending_children: Mapping<Id, Vec<Child>>,
+    /// Parent of each token of this collection nested into another one.
+    pub parents: Mapping<Id, Id>,
+}
+
+pub trait AFT34NestableImpl: Internal + Storage<Data> + aft34::Internal {
+    fn add_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error> {
+        let accepted = match Internal::_check_parent_access(self, &parent_id) {
+            Ok(()) => true,
+            Err(AFT34Error::NotApproved) => false,
+            Err(error) => return Err(error),
+        };
+
+        Internal::_receive_child(self, &parent_id, &child)?;
+        Internal::_insert_child(self, parent_id, child, accepted);
+        Ok(())
+    }
+
+    fn accept_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error> {
+        Internal::_check_parent_access(self, &parent_id)?;
+        Internal::_accept_child(self, parent_id, child)
+    }
+
+    fn transfer_child(
+        &mut self,
+        parent_id: Id,
+        child: Child,
+        to: AccountId,
+    ) -> Result<(), AFT34Error> {
+        Internal::_check_parent_access(self, &parent_id)?;
+        Internal::_remove_child(self, &parent_id, &child)?;
+        Internal::_send_child(self, &child, to)?;
+        Internal::_emit_child_transferred_event(self, parent_id, child, to);
+        Ok(())
+    }
+
+    fn children(&self, parent_id: Id) -> Vec<Child> {
+        self.data::<Data>()
+            .children
+            .get(&parent_id)
+            .unwrap_or_default()
+    }
+
+    fn pending_children(&self, parent_id: Id) -> Vec<Child> {
+        self.data::<Data>()
+            .pending_children
+            .get(&parent_id)
+            .unwrap_or_default()
+    }
+
+    fn parent_of(&self, id: Id) -> Option<Id> {
+        self.data::<Data>().parents.get(&id)
+    }
+
+    fn root_owner_of(&self, id: Id) -> Option<AccountId> {
+        Internal::_root_of(self, &id).map(|(_, owner)| owner)
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when `child` is nested into `parent_id`, `pending` until it is accepted.
+    fn _emit_child_added_event(&self, parent_id: Id, child: Child, pending: bool);
+
+    /// Event is emitted when the pending `child` of `parent_id` is accepted.
+    fn _emit_child_accepted_event(&self, parent_id: Id, child: Child);
+
+    /// Event is emitted when `child` of `parent_id` is transferred out to `to`.
+    fn _emit_child_transferred_event(&self, parent_id: Id, child: Child, to: AccountId);
+
+    /// Returns the top-most parent of `id` and its owner.
+    fn _root_of(&self, id: &Id) -> Option<(Id, AccountId)>;
+
+    /// Checks that the caller owns the root token of `parent_id`, or is approved for it.
+    fn _check_parent_access(&self, parent_id: &Id) -> Result<(), AFT34Error>;
+
+    /// Checks that nesting `id` into `parent_id` doesn't make `id` its own ancestor.
+    fn _check_nesting_cycle(&self, parent_id: &Id, id: &Id) -> Result<(), AFT34Error>;
+
+    /// Transfers `child` from the caller to this contract.
+    fn _receive_child(&mut self, parent_id: &Id, child: &Child) -> Result<(), AFT34Error>;
+
+    /// Transfers `child` from this contract to `to`.
+    fn _send_child(&mut self, child: &Child, to: AccountId) -> Result<(), AFT34Error>;
+
+    /// Records `child` as an accepted or a pending child of `parent_id`.
+    fn _insert_child(&mut self, parent_id: Id, child: Child, accepted: bool);
+
+    fn _accept_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error>;
+
+    /// Removes `child` from the accepted or else the pending children of `parent_id`.
+    fn _remove_child(&mut self, parent_id: &Id, child: &Child) -> Result<(), AFT34Error>;
+
+    /// Moves the token `id` of this collection held by this contract to `to`.
+    fn _release_token(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error>;
+}
+
+pub trait InternalImpl:
+    Internal + Storage<Data> + aft34::Internal + aft34::BalancesManager
+{
+    fn _emit_child_added_event(&self, _parent_id: Id, _child: Child, _pending: bool) {}
+
+    fn _emit_child_accepted_event(&self, _parent_id: Id, _child: Child) {}
+
+    fn _emit_child_transferred_event(&self, _parent_id: Id, _child: Child, _to: AccountId) {}
+
+    fn _root_of(&self, id: &Id) -> Option<(Id, AccountId)> {
+        let mut root = id.clone();
+        while let Some(parent) = self.data::<Data>().parents.get(&root) {
+            root = parent;
+        }
+
+        let owner = aft34::Internal::_owner_of(self, &root)?;
+        Some((root, owner))
+    }
+
+    fn _check_parent_access(&self, parent_id: &Id) -> Result<(), AFT34Error> {
+        let (root, owner) =
+            Internal::_root_of(self, parent_id).ok_or(AFT34Error::TokenNotExists)?;
+        let caller = Self::env().caller();
+
+        if caller != owner && !self._allowance(&owner, &caller, &Some(&root)) {
+            return Err(AFT34Error::NotApproved);
+        }
+        Ok(())
+    }
+
+    fn _check_nesting_cycle(&self, parent_id: &Id, id: &Id) -> Result<(), AFT34Error> {
+        let mut ancestor = Some(parent_id.clone());
+        while let Some(current) = ancestor {
+            if &current == id {
+                return Err(AFT34Error::Custom(String::from("NestingCycle")));
+            }
+            ancestor = self.data::<Data>().parents.get(&current);
+        }
+        Ok(())
+    }
+
+    fn _receive_child(&mut self, parent_id: &Id, child: &Child) -> Result<(), AFT34Error> {
+        let this = Self::env().account_id();
+        let caller = Self::env().caller();
+
+        match child {
+            Child::AFT34 { contract, id } if *contract == this => {
+                Internal::_check_nesting_cycle(self, parent_id, id)?;
+                aft34::Internal::_transfer_token(self, this, id.clone(), Vec::new())?;
+                self.data::<Data>().parents.insert(id, parent_id);
+                Ok(())
+            }
+            Child::AFT34 { contract, id } => {
+                if AFT34Ref::owner_of(contract, id.clone()) != Some(caller) {
+                    return Err(AFT34Error::NotApproved);
+                }
+
+                AFT34Ref::transfer_builder(contract, this, id.clone(), Vec::new())
+                    .try_invoke()
+                    .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+                    .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+            }
+            Child::AFT37 {
+                contract,
+                id,
+                amount,
+            } => AFT37Ref::transfer_from_builder(
+                contract,
+                caller,
+                this,
+                id.clone(),
+                *amount,
+                Vec::new(),
+            )
+            .try_invoke()
+            .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+            .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+            .map_err(AFT34Error::from),
+        }
+    }
+
+    fn _send_child(&mut self, child: &Child, to: AccountId) -> Result<(), AFT34Error> {
+        match child {
+            Child::AFT34 { contract, id } if *contract == Self::env().account_id() => {
+                self.data::<Data>().parents.remove(id);
+                Internal::_release_token(self, to, id.clone())
+            }
+            Child::AFT34 { contract, id } => {
+                AFT34Ref::transfer_builder(contract, to, id.clone(), Vec::new())
+                    .try_invoke()
+                    .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+                    .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+            }
+            Child::AFT37 {
+                contract,
+                id,
+                amount,
+            } => AFT37Ref::transfer_builder(contract, to, id.clone(), *amount, Vec::new())
+                .try_invoke()
+                .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+                .map_err(|_| AFT34Error::Custom(String::from("ChildTransferFailed")))?
+                .map_err(AFT34Error::from),
+        }
+    }
+
+    fn _insert_child(&mut self, parent_id: Id, child: Child, accepted: bool) {
+        let data = self.data::<Data>();
+        let list = if accepted {
+            &mut data.children
+        } else {
+            &mut data.pending_children
+        };
+
+        let mut children = list.get(&parent_id).unwrap_or_default();
+        merge_child(&mut children, child.clone());
+        list.insert(&parent_id, &children);
+
+        Internal::_emit_child_added_event(self, parent_id, child, !accepted);
+    }
+
+    fn _accept_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error> {
+        let data = self.data::<Data>();
+        let mut pending = data.pending_children.get(&parent_id).unwrap_or_default();
+        take_child(&mut pending, &child)?;
+        store_children(&mut data.pending_children, &parent_id, pending);
+
+        let mut children = data.children.get(&parent_id).unwrap_or_default();
+        merge_child(&mut children, child.clone());
+        data.children.insert(&parent_id, &children);
+
+        Internal::_emit_child_accepted_event(self, parent_id, child);
+        Ok(())
+    }
+
+    fn _remove_child(&mut self, parent_id: &Id, child: &Child) -> Result<(), AFT34Error> {
+        let data = self.data::<Data>();
+        for list in [&mut data.children, &mut data.pending_children] {
+            let mut children = list.get(parent_id).unwrap_or_default();
+            if take_child(&mut children, child).is_ok() {
+                store_children(list, parent_id, children);
+                return Ok(());
+            }
+        }
+        Err(AFT34Error::Custom(String::from("ChildNotFound")))
+    }
+
+    fn _release_token(&mut self, to: AccountId, id: Id) -> Result<(), AFT34Error> {
+        let this = Self::env().account_id();
+        aft34::Internal::_before_token_transfer(self, Some(&this), Some(&to), &id)?;
+
+        self._remove_token_approval(&id);
+        aft34::BalancesManager::_decrease_balance(self, &this, &id, false);
+        self._remove_token_owner(&id);
+
+        aft34::BalancesManager::_increase_balance(self, &to, &id, false);
+        self._insert_token_owner(&id, &to);
+        aft34::Internal::_after_token_transfer(self, Some(&this), Some(&to), &id)?;
+        aft34::Internal::_emit_transfer_event(self, Some(this), Some(to), id);
+        Ok(())
+    }
+}
+
+/// Transfer hooks of [`aft34::Internal`] preventing the burn of tokens holding children, run before the default ones
+/// when the contract implements `AFT34Nestable`.
+pub trait AFT34TransferImpl: Storage<Data> {
+    fn _before_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), AFT34Error> {
+        let data = self.data::<Data>();
+        if to.is_none() && (data.children.contains(id) || data.pending_children.contains(id)) {
+            return Err(AFT34Error::Custom(String::from("ParentHasChildren")));
+        }
+        Ok(())
+    }
+
+    fn _after_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        _id: &Id,
+    ) -> Result<(), AFT34Error> {
+        Ok(())
+    }
+}
+
+/// Adds `child` to `children`, merging the AFT37 balances of the same token.
+fn merge_child(children: &mut Vec<Child>, child: Child) {
+    if let Child::AFT37 {
+        contract,
+        id,
+        amount,
+    } = &child
+    {
+        for existing in children.iter_mut() {
+            if let Child::AFT37 {
+                contract: held_contract,
+                id: held_id,
+                amount: held_amount,
+            } = existing
+            {
+                if held_contract == contract && held_id == id {
+                    *held_amount = held_amount.saturating_add(*amount);
+                    return;
+                }
+            }
+        }
+    }
+    children.push(child);
+}
+
+/// Removes `child` from `children`, only deducting its amount from a larger AFT37 balance.
+fn take_child(children: &mut Vec<Child>, child: &Child) -> Result<(), AFT34Error> {
+    let position = children
+        .iter()
+        .position(|existing| match (existing, child) {
+            (
+                Child::AFT37 {
+                    contract: held_contract,
+                    id: held_id,
+                    amount: held_amount,
+                },
+                Child::AFT37 {
+                    contract,
+                    id,
+                    amount,
+                },
+            ) => held_contract == contract && held_id == id && held_amount >= amount,
+            _ => existing == child,
+        })
+        .ok_or_else(|| AFT34Error::Custom(String::from("ChildNotFound")))?;
+
+    if let (
+        Child::AFT37 {
+            amount: held_amount,
+            ..
+        },
+        Child::AFT37 { amount, .. },
+    ) = (&mut children[position], child)
+    {
+        if *held_amount > *amount {
+            *held_amount -= *amount;
+            return Ok(());
+        }
+    }
+    children.remove(position);
+    Ok(())
+}
+
+fn store_children(list: &mut Mapping<Id, Vec<Child>>, parent_id: &Id, children: Vec<Child>) {
+    if children.is_empty() {
+        list.remove(parent_id);
+    } else {
+        list.insert(parent_id, &children);
+    }
+}
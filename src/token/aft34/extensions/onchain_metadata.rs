@@ -0,0 +1,132 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft34,
+    aft34::extensions::{metadata, onchain_metadata},
+    traits::aft34::{extensions::onchain_metadata::*, *},
+};
+pub use aft34::{
+    AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
+};
+use ink::prelude::format;
+pub use metadata::AFT34MetadataImpl;
+pub use onchain_metadata::Internal as _;
+use openbrush::traits::String;
+
+/// Prefix of the data URIs returned by [`AFT34OnchainMetadataImpl::token_uri`].
+pub const DATA_URI_PREFIX: &str = "data:application/json;base64,";
+
+/// Attributes rendered as top-level fields of the JSON metadata, in this order.
+pub const TOP_LEVEL_KEYS: [&str; 3] = ["name", "description", "image"];
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub trait AFT34OnchainMetadataImpl: Internal + aft34::Internal + AFT34MetadataImpl {
+    fn token_uri(&self, id: Id) -> Result<String, AFT34Error> {
+        aft34::Internal::_owner_of(self, &id).ok_or(AFT34Error::TokenNotExists)?;
+        let json = Internal::_metadata_json(self, &id);
+        Ok(String::from(DATA_URI_PREFIX) + &encode_base64(json.as_bytes()))
+    }
+}
+
+pub trait Internal {
+    /// Returns the JSON metadata document of `id`.
+    fn _metadata_json(&self, id: &Id) -> String;
+}
+
+pub trait InternalImpl: Internal + AFT34MetadataImpl {
+    fn _metadata_json(&self, id: &Id) -> String {
+        let mut json = String::from("{");
+
+        for key in TOP_LEVEL_KEYS {
+            if let Some(value) =
+                AFT34MetadataImpl::get_attribute(self, id.clone(), String::from(key))
+            {
+                push_json_string(&mut json, key);
+                json.push(':');
+                push_json_string(&mut json, &value);
+                json.push(',');
+            }
+        }
+
+        json.push_str("\"attributes\":[");
+        let traits = AFT34MetadataImpl::get_attributes(self, id.clone())
+            .into_iter()
+            .filter(|(key, _)| !TOP_LEVEL_KEYS.contains(&key.as_str()));
+        for (index, (key, value)) in traits.enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            json.push_str("{\"trait_type\":");
+            push_json_string(&mut json, &key);
+            json.push_str(",\"value\":");
+            push_json_string(&mut json, &value);
+            json.push('}');
+        }
+        json.push_str("]}");
+
+        json
+    }
+}
+
+/// Appends `value` to `json` as a quoted and escaped JSON string.
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Encodes `input` in padded standard base64.
+fn encode_base64(input: &[u8]) -> String {
+    let mut output = String::with_capacity((input.len() + 2) / 3 * 4);
+
+    for chunk in input.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or_default(),
+            chunk.get(2).copied().unwrap_or_default(),
+        ];
+        let triple = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (triple >> (18 - 6 * index)) & 0x3f;
+                output.push(char::from(BASE64_ALPHABET[sextet as usize]));
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
@@ -21,18 +21,32 @@
 
 pub use crate::{
     aft34,
-    traits::aft34::{extensions::metadata::*, extensions::payable_mint::*, *},
+    aft34::extensions::payable_mint,
+    traits::{
+        aft22::AFT22Ref,
+        aft34::{extensions::payable_mint::*, *},
+    },
 };
 pub use aft34::{
     AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
 };
-use ink::prelude::string::{String, ToString};
+use ink::{
+    env::hash::Keccak256,
+    prelude::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    },
+};
+pub use payable_mint::Internal as _;
 use openbrush::{
     contracts::ownable::*,
+    storage::{Mapping, TypeGuard},
     traits::{AccountId, Balance, Storage},
 };
 
-use super::metadata::AFT34MetadataImpl;
+/// Suffix appended to the token URIs unless another one is set.
+pub const DEFAULT_URI_SUFFIX: &str = ".json";
 
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
@@ -40,69 +54,82 @@ pub struct Data {
     pub last_token_id: u64,
     pub max_supply: u64,
     pub price_per_mint: Balance,
+    pub base_uri: Option<String>,
+    pub uri_suffix: Option<String>,
+    pub phases: Mapping<u32, SalePhase>,
+    pub phases_count: u32,
+    pub phase_minted: Mapping<u32, u64>,
+    pub wallet_minted: Mapping<(u32, AccountId), u64, WalletMintedKey>,
+    pub payment_token: Option<AccountId>,
+    pub payout_address: Option<AccountId>,
+    pub payout_splits: Vec<(AccountId, u32)>,
+}
+
+pub struct WalletMintedKey;
+
+impl<'a> TypeGuard<'a> for WalletMintedKey {
+    type Type = &'a (&'a u32, &'a AccountId);
 }
 
 pub trait AFT34PayableMintImpl:
-    aft34::Internal
-    + aft34::aft34::AFT34Impl
-    + aft34::extensions::metadata::Internal
-    + aft34::extensions::payable_mint::Internal
-    + aft34::extensions::metadata::AFT34MetadataImpl
-    + Storage<Data>
-    + Storage<ownable::Data>
+    aft34::Internal + aft34::aft34::AFT34Impl + Internal + Storage<Data> + Storage<ownable::Data>
 {
     fn mint(&mut self, to: AccountId, mint_amount: u64) -> Result<(), AFT34Error> {
-        self.check_value(Self::env().transferred_value(), mint_amount)?;
-        self.check_amount(mint_amount)?;
-
-        let next_to_mint = self.data::<Data>().last_token_id + 1; // first mint id is 1
-        let mint_offset = next_to_mint + mint_amount;
-
-        for mint_id in next_to_mint..mint_offset {
-            self._mint_to(to, Id::U64(mint_id))?;
-            self.data::<Data>().last_token_id += 1;
-        }
+        Internal::_mint_payable(self, to, mint_amount, Vec::new())
+    }
 
-        Ok(())
+    fn mint_allowlisted(
+        &mut self,
+        to: AccountId,
+        mint_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), AFT34Error> {
+        Internal::_mint_payable(self, to, mint_amount, proof)
     }
 
-    /// Withdraws funds to contract owner
+    /// Withdraws funds, and the payment token balance if any, to the payout splits,
+    /// the payout address or the contract owner
     #[openbrush::modifiers(only_owner)]
     fn withdraw(&mut self) -> Result<(), AFT34Error> {
-        let balance = Self::env().balance();
-        let current_balance = balance
-            .checked_sub(Self::env().minimum_balance())
-            .unwrap_or_default();
-        let owner = self.data::<ownable::Data>().owner.get().unwrap().unwrap();
-        Self::env()
-            .transfer(owner, current_balance)
-            .map_err(|_| AFT34Error::Custom(String::from("WithdrawalFailed")))?;
-        Ok(())
+        if let Some(payment_token) = self.data::<Data>().payment_token {
+            Internal::_withdraw_token(self, payment_token)?;
+        }
+        Internal::_withdraw_native(self)
     }
 
     /// Set new value for the baseUri
     #[openbrush::modifiers(only_owner)]
     fn set_base_uri(&mut self, uri: String) -> Result<(), AFT34Error> {
-        let id = AFT34Impl::collection_id(self);
-        aft34::extensions::metadata::Internal::_set_attribute(
-            self,
-            id,
-            String::from("baseUri"),
-            uri,
-        );
+        Internal::_set_base_uri(self, Some(uri));
+        Ok(())
+    }
 
+    /// Set new value for the suffix appended to the token URIs
+    #[openbrush::modifiers(only_owner)]
+    fn set_uri_suffix(&mut self, uri_suffix: Option<String>) -> Result<(), AFT34Error> {
+        Internal::_set_uri_suffix(self, uri_suffix);
         Ok(())
     }
 
+    /// Get the baseUri
+    fn base_uri(&self) -> Option<String> {
+        self.data::<Data>().base_uri.clone()
+    }
+
     /// Get URI from token ID
-    fn token_uri(&self, token_id: u64) -> Result<String, AFT34Error> {
-        let base_uri =
-            AFT34MetadataImpl::get_attribute(self, Id::U64(token_id), String::from("baseUri"));
-        let token_uri = base_uri
-            .ok_or_else(|| AFT34Error::Custom(String::from("InvalidBaseUri")))?
-            + &token_id.to_string()
-            + &String::from(".json");
-        Ok(token_uri)
+    fn token_uri(&self, token_id: Id) -> Result<Option<String>, AFT34Error> {
+        self.token_exists(token_id.clone())?;
+        let id = Internal::_render_id(self, &token_id);
+        let suffix = self
+            .data::<Data>()
+            .uri_suffix
+            .clone()
+            .unwrap_or_else(|| String::from(DEFAULT_URI_SUFFIX));
+        Ok(self
+            .data::<Data>()
+            .base_uri
+            .clone()
+            .map(|base_uri| base_uri + &id + &suffix))
     }
 
     /// Get max supply of tokens
@@ -112,22 +139,198 @@ pub trait AFT34PayableMintImpl:
 
     /// Get token price
     fn price(&self) -> Balance {
-        self.data::<Data>().price_per_mint
+        Internal::_current_price(self)
+    }
+
+    /// Add or replace a phase of the sale
+    #[openbrush::modifiers(only_owner)]
+    fn set_phase(&mut self, phase_id: u32, phase: SalePhase) -> Result<(), AFT34Error> {
+        Internal::_set_phase(self, phase_id, phase)
+    }
+
+    fn phase(&self, phase_id: u32) -> Option<SalePhase> {
+        self.data::<Data>().phases.get(&phase_id)
+    }
+
+    fn phases_count(&self) -> u32 {
+        self.data::<Data>().phases_count
+    }
+
+    fn active_phase(&self) -> Option<(u32, SalePhase)> {
+        Internal::_active_phase(self)
+    }
+
+    fn remaining_allocation(&self, wallet: AccountId) -> u64 {
+        Internal::_remaining_allocation(self, &wallet)
+    }
+
+    /// Set the AFT22 token mints are paid in, or `None` to pay in the native balance
+    #[openbrush::modifiers(only_owner)]
+    fn set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<(), AFT34Error> {
+        Internal::_set_payment_token(self, payment_token)
+    }
+
+    fn payment_token(&self) -> Option<AccountId> {
+        self.data::<Data>().payment_token
+    }
+
+    /// Set the recipient of withdrawals, or `None` to pay the contract owner
+    #[openbrush::modifiers(only_owner)]
+    fn set_payout_address(&mut self, payout_address: Option<AccountId>) -> Result<(), AFT34Error> {
+        Internal::_set_payout_address(self, payout_address);
+        Ok(())
+    }
+
+    fn payout_address(&self) -> Option<AccountId> {
+        self.data::<Data>().payout_address
+    }
+
+    /// Set the weighted recipients withdrawals are split between, or an empty list to disable splits
+    #[openbrush::modifiers(only_owner)]
+    fn set_payout_splits(&mut self, splits: Vec<(AccountId, u32)>) -> Result<(), AFT34Error> {
+        Internal::_set_payout_splits(self, splits)
+    }
+
+    fn payout_splits(&self) -> Vec<(AccountId, u32)> {
+        self.data::<Data>().payout_splits.clone()
     }
 }
 
-pub trait Internal: Storage<Data> + aft34::Internal {
-    /// Check if the transferred mint values is as expected
+pub trait Internal {
+    /// Check if the transferred mint value covers the price of `mint_amount` tokens
+    fn check_value(&self, transferred_value: u128, mint_amount: u64) -> Result<(), AFT34Error>;
+
+    /// Check amount of tokens to be minted
+    fn check_amount(&self, mint_amount: u64) -> Result<(), AFT34Error>;
+
+    /// Check if token is minted
+    fn token_exists(&self, id: Id) -> Result<(), AFT34Error>;
+
+    /// Mints `mint_amount` tokens to `to` against the transferred value, within the limits of the active phase.
+    ///
+    /// The tokens are minted as a single consecutive batch of `Id::U64` ids, or one by one under their
+    /// canonical ids if the id policy sets `canonical_integers`.
+    fn _mint_payable(
+        &mut self,
+        to: AccountId,
+        mint_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), AFT34Error>;
+
+    /// Checks that `wallet` can mint `mint_amount` tokens during the active phase,
+    /// and returns the id of that phase.
+    ///
+    /// Returns `None` if no phase is configured.
+    fn _check_phase(
+        &self,
+        wallet: &AccountId,
+        mint_amount: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<Option<u32>, AFT34Error>;
+
+    /// Adds a phase if `phase_id` is the number of phases, otherwise replaces the phase `phase_id`.
+    fn _set_phase(&mut self, phase_id: u32, phase: SalePhase) -> Result<(), AFT34Error>;
+
+    /// Returns the first phase open at the current block timestamp.
+    fn _active_phase(&self) -> Option<(u32, SalePhase)>;
+
+    /// Returns the price of the active phase, or the default price if no phase is active.
+    fn _current_price(&self) -> Balance;
+
+    /// Returns how many tokens `wallet` can still mint during the active phase.
+    fn _remaining_allocation(&self, wallet: &AccountId) -> u64;
+
+    /// Collects the price of `mint_amount` tokens from `payer`, in the payment token if any,
+    /// otherwise from the transferred value.
+    fn _collect_payment(&mut self, payer: AccountId, mint_amount: u64) -> Result<(), AFT34Error>;
+
+    /// Transfers `amount` of `payment_token` from `payer` to the contract.
+    fn _pull_payment(
+        &mut self,
+        payment_token: AccountId,
+        payer: AccountId,
+        amount: Balance,
+    ) -> Result<(), AFT34Error>;
+
+    /// Transfers `amount` of `payment_token` from the contract to `to`.
+    fn _push_payment(
+        &mut self,
+        payment_token: AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the recipient of withdrawals: the payout address if set, otherwise the contract owner.
+    fn _payee(&self) -> Result<AccountId, AFT34Error>;
+
+    /// Splits `amount` between the payout splits by weight, the last recipient receiving the rounding dust.
+    ///
+    /// Without payout splits, the whole amount goes to `_payee`.
+    fn _payouts(&self, amount: Balance) -> Result<Vec<(AccountId, Balance)>, AFT34Error>;
+
+    /// Sends `amount` of the native balance back to `to`.
+    fn _refund(&mut self, to: AccountId, amount: Balance) -> Result<(), AFT34Error>;
+
+    /// Pays out the whole balance of the contract in `payment_token` to the payees.
+    fn _withdraw_token(&mut self, payment_token: AccountId) -> Result<(), AFT34Error>;
+
+    /// Pays out the native balance of the contract, above the minimum balance, to the payees.
+    fn _withdraw_native(&mut self) -> Result<(), AFT34Error>;
+
+    /// Maximum amount of payout splits, bounding the transfers made by a withdrawal.
+    fn _max_payout_splits(&self) -> u32;
+
+    /// Sets the weighted recipients withdrawals are split between, an empty list paying `_payee` instead.
+    ///
+    /// Fails with `Custom("InvalidPayoutSplits")` if a weight is zero, or `Custom("TooManyPayoutSplits")`
+    /// if there are more than `_max_payout_splits` recipients.
+    fn _set_payout_splits(&mut self, splits: Vec<(AccountId, u32)>) -> Result<(), AFT34Error>;
+
+    /// Sets the payment token, first paying out the balance held in the previous payment token
+    /// so that it is not stranded.
+    ///
+    /// The native balance is always paid out by `withdraw`, whatever the payment token.
+    fn _set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<(), AFT34Error>;
+
+    /// Sets the recipient of withdrawals without payout splits, `None` paying the contract owner.
+    fn _set_payout_address(&mut self, payout_address: Option<AccountId>);
+
+    /// Returns `true` if `proof` proves that `wallet` is a leaf of the merkle tree of `merkle_root`.
+    ///
+    /// Leaves are the keccak256 hash of the account, nodes the keccak256 hash of their sorted children.
+    fn _verify_allowlist(
+        &self,
+        merkle_root: &[u8; 32],
+        wallet: &AccountId,
+        proof: &[[u8; 32]],
+    ) -> bool;
+
+    /// Sets the baseUri the token URIs are derived from.
+    fn _set_base_uri(&mut self, base_uri: Option<String>);
+
+    /// Sets the suffix appended to the token URIs, `None` restoring the default `.json` suffix.
+    ///
+    /// Use an empty suffix to derive the token URIs without any suffix.
+    fn _set_uri_suffix(&mut self, uri_suffix: Option<String>);
+
+    /// Renders `id` as used in the token URIs.
+    ///
+    /// Integer ids are rendered in decimal, bytes ids in lowercase hexadecimal with a `0x` prefix.
+    fn _render_id(&self, id: &Id) -> String;
+}
+
+pub trait InternalImpl:
+    Internal + Storage<Data> + Storage<ownable::Data> + aft34::Internal
+{
     fn check_value(&self, transferred_value: u128, mint_amount: u64) -> Result<(), AFT34Error> {
-        if let Some(value) = (mint_amount as u128).checked_mul(self.data::<Data>().price_per_mint) {
-            if transferred_value == value {
+        if let Some(value) = (mint_amount as u128).checked_mul(Internal::_current_price(self)) {
+            if transferred_value >= value {
                 return Ok(());
             }
         }
         Err(AFT34Error::Custom(String::from("BadMintValue")))
     }
 
-    /// Check amount of tokens to be minted
     fn check_amount(&self, mint_amount: u64) -> Result<(), AFT34Error> {
         if mint_amount == 0 {
             return Err(AFT34Error::Custom(String::from("CannotMintZeroTokens")));
@@ -140,9 +343,343 @@ pub trait Internal: Storage<Data> + aft34::Internal {
         Err(AFT34Error::Custom(String::from("CollectionIsFull")))
     }
 
-    /// Check if token is minted
     fn token_exists(&self, id: Id) -> Result<(), AFT34Error> {
         self._owner_of(&id).ok_or(AFT34Error::TokenNotExists)?;
         Ok(())
     }
+
+    fn _mint_payable(
+        &mut self,
+        to: AccountId,
+        mint_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), AFT34Error> {
+        let caller = Self::env().caller();
+        let phase_id = Internal::_check_phase(self, &caller, mint_amount, &proof)?;
+        self.check_amount(mint_amount)?;
+        Internal::_collect_payment(self, caller, mint_amount)?;
+
+        let next_to_mint = self.data::<Data>().last_token_id + 1; // first mint id is 1
+        if aft34::Internal::_id_policy(self).canonical_integers {
+            for id in next_to_mint..next_to_mint + mint_amount {
+                aft34::Internal::_mint_to(self, to, Id::U64(id))?;
+            }
+        } else {
+            self._mint_consecutive(to, Id::U64(next_to_mint), mint_amount as u128)?;
+        }
+        self.data::<Data>().last_token_id += mint_amount;
+
+        if let Some(phase_id) = phase_id {
+            let phase_minted = self
+                .data::<Data>()
+                .phase_minted
+                .get(&phase_id)
+                .unwrap_or_default();
+            self.data::<Data>()
+                .phase_minted
+                .insert(&phase_id, &(phase_minted + mint_amount));
+
+            let wallet_minted = self
+                .data::<Data>()
+                .wallet_minted
+                .get(&(&phase_id, &caller))
+                .unwrap_or_default();
+            self.data::<Data>()
+                .wallet_minted
+                .insert(&(&phase_id, &caller), &(wallet_minted + mint_amount));
+        }
+
+        Ok(())
+    }
+
+    fn _check_phase(
+        &self,
+        wallet: &AccountId,
+        mint_amount: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<Option<u32>, AFT34Error> {
+        if self.data::<Data>().phases_count == 0 {
+            return Ok(None);
+        }
+
+        let (phase_id, phase) = Internal::_active_phase(self)
+            .ok_or_else(|| AFT34Error::Custom(String::from("NoActivePhase")))?;
+
+        if let Some(merkle_root) = phase.merkle_root {
+            if !Internal::_verify_allowlist(self, &merkle_root, wallet, proof) {
+                return Err(AFT34Error::Custom(String::from("NotAllowlisted")));
+            }
+        }
+
+        if let Some(supply) = phase.supply {
+            let phase_minted = self
+                .data::<Data>()
+                .phase_minted
+                .get(&phase_id)
+                .unwrap_or_default();
+            if phase_minted.saturating_add(mint_amount) > supply {
+                return Err(AFT34Error::Custom(String::from("PhaseSoldOut")));
+            }
+        }
+
+        if let Some(max_per_wallet) = phase.max_per_wallet {
+            let wallet_minted = self
+                .data::<Data>()
+                .wallet_minted
+                .get(&(&phase_id, wallet))
+                .unwrap_or_default();
+            if wallet_minted.saturating_add(mint_amount) > max_per_wallet {
+                return Err(AFT34Error::Custom(String::from("WalletLimitReached")));
+            }
+        }
+
+        Ok(Some(phase_id))
+    }
+
+    fn _set_phase(&mut self, phase_id: u32, phase: SalePhase) -> Result<(), AFT34Error> {
+        let phases_count = self.data::<Data>().phases_count;
+        if phase_id > phases_count || phase.start >= phase.end {
+            return Err(AFT34Error::Custom(String::from("InvalidPhase")));
+        }
+
+        self.data::<Data>().phases.insert(&phase_id, &phase);
+        if phase_id == phases_count {
+            self.data::<Data>().phases_count += 1;
+        }
+        Ok(())
+    }
+
+    fn _active_phase(&self) -> Option<(u32, SalePhase)> {
+        let now = Self::env().block_timestamp();
+        (0..self.data::<Data>().phases_count).find_map(|phase_id| {
+            self.data::<Data>()
+                .phases
+                .get(&phase_id)
+                .filter(|phase| phase.start <= now && now < phase.end)
+                .map(|phase| (phase_id, phase))
+        })
+    }
+
+    fn _current_price(&self) -> Balance {
+        Internal::_active_phase(self)
+            .map(|(_, phase)| phase.price)
+            .unwrap_or(self.data::<Data>().price_per_mint)
+    }
+
+    fn _remaining_allocation(&self, wallet: &AccountId) -> u64 {
+        let remaining_supply = self
+            .data::<Data>()
+            .max_supply
+            .saturating_sub(self.data::<Data>().last_token_id);
+        if self.data::<Data>().phases_count == 0 {
+            return remaining_supply;
+        }
+
+        let (phase_id, phase) = match Internal::_active_phase(self) {
+            Some(active_phase) => active_phase,
+            None => return 0,
+        };
+
+        let mut remaining = remaining_supply;
+        if let Some(supply) = phase.supply {
+            let phase_minted = self
+                .data::<Data>()
+                .phase_minted
+                .get(&phase_id)
+                .unwrap_or_default();
+            remaining = remaining.min(supply.saturating_sub(phase_minted));
+        }
+        if let Some(max_per_wallet) = phase.max_per_wallet {
+            let wallet_minted = self
+                .data::<Data>()
+                .wallet_minted
+                .get(&(&phase_id, wallet))
+                .unwrap_or_default();
+            remaining = remaining.min(max_per_wallet.saturating_sub(wallet_minted));
+        }
+        remaining
+    }
+
+    fn _collect_payment(&mut self, payer: AccountId, mint_amount: u64) -> Result<(), AFT34Error> {
+        let transferred_value = Self::env().transferred_value();
+
+        match self.data::<Data>().payment_token {
+            None => {
+                self.check_value(transferred_value, mint_amount)?;
+                let excess = transferred_value
+                    - (mint_amount as u128).saturating_mul(Internal::_current_price(self));
+                if excess > 0 {
+                    Internal::_refund(self, payer, excess)?;
+                }
+                Ok(())
+            }
+            Some(payment_token) => {
+                if transferred_value != 0 {
+                    return Err(AFT34Error::Custom(String::from("BadMintValue")));
+                }
+                let value = (mint_amount as u128)
+                    .checked_mul(Internal::_current_price(self))
+                    .ok_or_else(|| AFT34Error::Custom(String::from("BadMintValue")))?;
+                Internal::_pull_payment(self, payment_token, payer, value)
+            }
+        }
+    }
+
+    fn _pull_payment(
+        &mut self,
+        payment_token: AccountId,
+        payer: AccountId,
+        amount: Balance,
+    ) -> Result<(), AFT34Error> {
+        AFT22Ref::transfer_from_builder(
+            &payment_token,
+            payer,
+            Self::env().account_id(),
+            amount,
+            Vec::<u8>::new(),
+        )
+        .try_invoke()
+        .map_err(|_| AFT34Error::Custom(String::from("PaymentFailed")))?
+        .map_err(|_| AFT34Error::Custom(String::from("PaymentFailed")))?
+        .map_err(AFT34Error::from)
+    }
+
+    fn _push_payment(
+        &mut self,
+        payment_token: AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), AFT34Error> {
+        AFT22Ref::transfer_builder(&payment_token, to, amount, Vec::<u8>::new())
+            .try_invoke()
+            .map_err(|_| AFT34Error::Custom(String::from("WithdrawalFailed")))?
+            .map_err(|_| AFT34Error::Custom(String::from("WithdrawalFailed")))?
+            .map_err(AFT34Error::from)
+    }
+
+    fn _payee(&self) -> Result<AccountId, AFT34Error> {
+        self.data::<Data>()
+            .payout_address
+            .or_else(|| self.data::<ownable::Data>().owner.get().flatten())
+            .ok_or_else(|| AFT34Error::Custom(String::from("NoPayee")))
+    }
+
+    fn _payouts(&self, amount: Balance) -> Result<Vec<(AccountId, Balance)>, AFT34Error> {
+        let splits = &self.data::<Data>().payout_splits;
+        if splits.is_empty() {
+            return Ok(vec![(Internal::_payee(self)?, amount)]);
+        }
+
+        let total_weight: u128 = splits.iter().map(|(_, weight)| *weight as u128).sum();
+        let mut remaining = amount;
+        let mut payouts = Vec::with_capacity(splits.len());
+        for (index, (payee, weight)) in splits.iter().enumerate() {
+            let share = if index == splits.len() - 1 {
+                remaining
+            } else {
+                // Split the division to avoid overflowing on large balances
+                (amount / total_weight) * *weight as u128
+                    + (amount % total_weight) * *weight as u128 / total_weight
+            };
+            remaining -= share;
+            payouts.push((*payee, share));
+        }
+        Ok(payouts)
+    }
+
+    fn _refund(&mut self, to: AccountId, amount: Balance) -> Result<(), AFT34Error> {
+        Self::env()
+            .transfer(to, amount)
+            .map_err(|_| AFT34Error::Custom(String::from("RefundFailed")))
+    }
+
+    fn _withdraw_token(&mut self, payment_token: AccountId) -> Result<(), AFT34Error> {
+        let token_balance = AFT22Ref::balance_of(&payment_token, Self::env().account_id());
+        for (payee, amount) in Internal::_payouts(self, token_balance)? {
+            if amount > 0 {
+                Internal::_push_payment(self, payment_token, payee, amount)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn _withdraw_native(&mut self) -> Result<(), AFT34Error> {
+        let balance = Self::env().balance();
+        let current_balance = balance
+            .checked_sub(Self::env().minimum_balance())
+            .unwrap_or_default();
+        for (payee, amount) in Internal::_payouts(self, current_balance)? {
+            if amount > 0 {
+                Self::env()
+                    .transfer(payee, amount)
+                    .map_err(|_| AFT34Error::Custom(String::from("WithdrawalFailed")))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn _max_payout_splits(&self) -> u32 {
+        10
+    }
+
+    fn _set_payout_splits(&mut self, splits: Vec<(AccountId, u32)>) -> Result<(), AFT34Error> {
+        if splits.len() > Internal::_max_payout_splits(self) as usize {
+            return Err(AFT34Error::Custom(String::from("TooManyPayoutSplits")));
+        }
+        if splits.iter().any(|(_, weight)| *weight == 0) {
+            return Err(AFT34Error::Custom(String::from("InvalidPayoutSplits")));
+        }
+        self.data::<Data>().payout_splits = splits;
+        Ok(())
+    }
+
+    fn _set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<(), AFT34Error> {
+        if let Some(previous) = self.data::<Data>().payment_token {
+            if payment_token != Some(previous) {
+                Internal::_withdraw_token(self, previous)?;
+            }
+        }
+        self.data::<Data>().payment_token = payment_token;
+        Ok(())
+    }
+
+    fn _set_payout_address(&mut self, payout_address: Option<AccountId>) {
+        self.data::<Data>().payout_address = payout_address;
+    }
+
+    fn _verify_allowlist(
+        &self,
+        merkle_root: &[u8; 32],
+        wallet: &AccountId,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        let mut node = [0u8; 32];
+        ink::env::hash_bytes::<Keccak256>(wallet.as_ref(), &mut node);
+
+        for sibling in proof {
+            let (left, right) = if node <= *sibling {
+                (node, *sibling)
+            } else {
+                (*sibling, node)
+            };
+            let mut pair = [0u8; 64];
+            pair[..32].copy_from_slice(&left);
+            pair[32..].copy_from_slice(&right);
+            ink::env::hash_bytes::<Keccak256>(&pair, &mut node);
+        }
+
+        &node == merkle_root
+    }
+
+    fn _set_base_uri(&mut self, base_uri: Option<String>) {
+        self.data::<Data>().base_uri = base_uri;
+    }
+
+    fn _set_uri_suffix(&mut self, uri_suffix: Option<String>) {
+        self.data::<Data>().uri_suffix = uri_suffix;
+    }
+
+    fn _render_id(&self, id: &Id) -> String {
+        id.value().to_string()
+    }
 }
@@ -0,0 +1,148 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft34,
+    aft34::extensions::rentable,
+    traits::aft34::{extensions::rentable::*, *},
+};
+pub use aft34::{
+    AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
+};
+pub use rentable::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{AccountId, Storage, Timestamp},
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    /// User of each rented token, with the end of the rental.
+    pub users: Mapping<Id, (AccountId, Timestamp)>,
+}
+
+pub trait AFT34RentableImpl: Internal + Storage<Data> + aft34::Internal {
+    fn set_user(
+        &mut self,
+        id: Id,
+        user: Option<AccountId>,
+        expires: Timestamp,
+    ) -> Result<(), AFT34Error> {
+        let owner = self._check_token_exists(&id)?;
+        let caller = Self::env().caller();
+        if caller != owner && !self._allowance(&owner, &caller, &Some(&id)) {
+            return Err(AFT34Error::NotApproved);
+        }
+
+        Internal::_set_user(self, id, user, expires)
+    }
+
+    fn user_of(&self, id: Id) -> Option<AccountId> {
+        Internal::_user_of(self, &id)
+    }
+
+    fn user_expires(&self, id: Id) -> Option<Timestamp> {
+        self.data::<Data>()
+            .users
+            .get(&id)
+            .map(|(_, expires)| expires)
+            .filter(|expires| *expires > Self::env().block_timestamp())
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when the user of a token changes, `user` being `None` when it is cleared.
+    fn _emit_update_user_event(&self, id: Id, user: Option<AccountId>, expires: Timestamp);
+
+    fn _set_user(
+        &mut self,
+        id: Id,
+        user: Option<AccountId>,
+        expires: Timestamp,
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the user of `id` if the rental did not expire yet.
+    fn _user_of(&self, id: &Id) -> Option<AccountId>;
+
+    /// Clears the user of `id`, if any.
+    fn _clear_user(&mut self, id: &Id);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_update_user_event(&self, _id: Id, _user: Option<AccountId>, _expires: Timestamp) {}
+
+    fn _set_user(
+        &mut self,
+        id: Id,
+        user: Option<AccountId>,
+        expires: Timestamp,
+    ) -> Result<(), AFT34Error> {
+        match user {
+            Some(user) => {
+                self.data::<Data>().users.insert(&id, &(user, expires));
+                Internal::_emit_update_user_event(self, id, Some(user), expires);
+            }
+            None => Internal::_clear_user(self, &id),
+        }
+        Ok(())
+    }
+
+    fn _user_of(&self, id: &Id) -> Option<AccountId> {
+        self.data::<Data>()
+            .users
+            .get(id)
+            .filter(|(_, expires)| *expires > Self::env().block_timestamp())
+            .map(|(user, _)| user)
+    }
+
+    fn _clear_user(&mut self, id: &Id) {
+        if self.data::<Data>().users.get(id).is_some() {
+            self.data::<Data>().users.remove(id);
+            Internal::_emit_update_user_event(self, id.clone(), None, 0);
+        }
+    }
+}
+
+/// Transfer hooks of [`aft34::Internal`] clearing the user of the moved token, run before the default ones
+/// when the contract implements `AFT34Rentable`.
+pub trait AFT34TransferImpl: Internal {
+    fn _before_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        _id: &Id,
+    ) -> Result<(), AFT34Error> {
+        Ok(())
+    }
+
+    fn _after_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), AFT34Error> {
+        if from.is_some() && from != to {
+            Internal::_clear_user(self, id);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft34,
+    aft34::extensions::reveal,
+    traits::aft34::{extensions::reveal::*, *},
+};
+pub use aft34::{
+    AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
+};
+use ink::{env::hash::Keccak256, prelude::format};
+pub use reveal::Internal as _;
+use openbrush::{
+    contracts::ownable::*,
+    traits::{Storage, String},
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub provenance: Option<[u8; 32]>,
+    /// Keccak256 hash of the reveal seed, committed along with the provenance hash.
+    pub seed_commitment: Option<[u8; 32]>,
+    pub starting_offset: Option<u64>,
+    pub collection_size: u64,
+    pub placeholder_uri: Option<String>,
+    pub base_uri: Option<String>,
+}
+
+pub trait AFT34RevealImpl:
+    Internal + Storage<Data> + Storage<ownable::Data> + aft34::Internal
+{
+    #[openbrush::modifiers(only_owner)]
+    fn reveal(&mut self, seed: [u8; 32]) -> Result<(), AFT34Error> {
+        Internal::_reveal(self, seed)
+    }
+
+    #[openbrush::modifiers(only_owner)]
+    fn set_provenance(
+        &mut self,
+        provenance: [u8; 32],
+        seed_commitment: [u8; 32],
+    ) -> Result<(), AFT34Error> {
+        Internal::_set_provenance(self, provenance, seed_commitment)
+    }
+
+    fn token_uri(&self, id: Id) -> Result<Option<String>, AFT34Error> {
+        self._owner_of(&id).ok_or(AFT34Error::TokenNotExists)?;
+
+        match Internal::_metadata_id(self, &id)? {
+            None => Ok(self.data::<Data>().placeholder_uri.clone()),
+            Some(metadata_id) => Ok(self
+                .data::<Data>()
+                .base_uri
+                .clone()
+                .map(|base_uri| base_uri + &format!("{}", metadata_id))),
+        }
+    }
+
+    fn is_revealed(&self) -> bool {
+        self.data::<Data>().starting_offset.is_some()
+    }
+
+    fn provenance(&self) -> Option<[u8; 32]> {
+        self.data::<Data>().provenance
+    }
+
+    fn seed_commitment(&self) -> Option<[u8; 32]> {
+        self.data::<Data>().seed_commitment
+    }
+
+    fn starting_offset(&self) -> Option<u64> {
+        self.data::<Data>().starting_offset
+    }
+
+    fn metadata_id(&self, id: Id) -> Result<Option<u64>, AFT34Error> {
+        Internal::_metadata_id(self, &id)
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when the collection is revealed.
+    fn _emit_reveal_event(&self, seed: [u8; 32], starting_offset: u64);
+
+    /// Commits to the metadata of the collection and to the reveal seed, through its Keccak256 hash.
+    ///
+    /// Fails once a token is minted, so that the owner can't pick them knowing the holders.
+    fn _set_provenance(
+        &mut self,
+        provenance: [u8; 32],
+        seed_commitment: [u8; 32],
+    ) -> Result<(), AFT34Error>;
+
+    /// Sets the URI served for every token until the reveal.
+    fn _set_placeholder_uri(&mut self, placeholder_uri: Option<String>);
+
+    /// Sets the baseUri the metadata ids are appended to after the reveal.
+    fn _set_base_uri(&mut self, base_uri: Option<String>);
+
+    /// Sets the amount of metadata the tokens are mapped to, ids going from 1 to `collection_size`.
+    ///
+    /// Fails once a token is minted.
+    fn _set_collection_size(&mut self, collection_size: u64) -> Result<(), AFT34Error>;
+
+    /// Reveals the collection with `seed`, which must match the committed seed hash.
+    fn _reveal(&mut self, seed: [u8; 32]) -> Result<(), AFT34Error>;
+
+    /// Derives the starting offset from `seed` and the provenance hash.
+    fn _derive_starting_offset(&self, seed: &[u8; 32]) -> u64;
+
+    /// Returns the metadata id `id` maps to, `None` until the collection is revealed.
+    fn _metadata_id(&self, id: &Id) -> Result<Option<u64>, AFT34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + aft34::BalancesManager {
+    fn _emit_reveal_event(&self, _seed: [u8; 32], _starting_offset: u64) {}
+
+    fn _set_provenance(
+        &mut self,
+        provenance: [u8; 32],
+        seed_commitment: [u8; 32],
+    ) -> Result<(), AFT34Error> {
+        if self.data::<Data>().provenance.is_some() {
+            return Err(AFT34Error::Custom(String::from("ProvenanceAlreadySet")));
+        }
+        if self._total_supply() > 0 {
+            return Err(AFT34Error::Custom(String::from("AlreadyMinted")));
+        }
+        self.data::<Data>().provenance = Some(provenance);
+        self.data::<Data>().seed_commitment = Some(seed_commitment);
+        Ok(())
+    }
+
+    fn _set_placeholder_uri(&mut self, placeholder_uri: Option<String>) {
+        self.data::<Data>().placeholder_uri = placeholder_uri;
+    }
+
+    fn _set_base_uri(&mut self, base_uri: Option<String>) {
+        self.data::<Data>().base_uri = base_uri;
+    }
+
+    fn _set_collection_size(&mut self, collection_size: u64) -> Result<(), AFT34Error> {
+        if self.data::<Data>().starting_offset.is_some() {
+            return Err(AFT34Error::Custom(String::from("AlreadyRevealed")));
+        }
+        if self._total_supply() > 0 {
+            return Err(AFT34Error::Custom(String::from("AlreadyMinted")));
+        }
+        self.data::<Data>().collection_size = collection_size;
+        Ok(())
+    }
+
+    fn _reveal(&mut self, seed: [u8; 32]) -> Result<(), AFT34Error> {
+        if self.data::<Data>().starting_offset.is_some() {
+            return Err(AFT34Error::Custom(String::from("AlreadyRevealed")));
+        }
+        let seed_commitment = match self.data::<Data>().seed_commitment {
+            Some(seed_commitment) => seed_commitment,
+            None => return Err(AFT34Error::Custom(String::from("ProvenanceNotSet"))),
+        };
+        if self.data::<Data>().collection_size == 0 {
+            return Err(AFT34Error::Custom(String::from("InvalidCollectionSize")));
+        }
+
+        let mut seed_hash = [0u8; 32];
+        ink::env::hash_bytes::<Keccak256>(&seed, &mut seed_hash);
+        if seed_hash != seed_commitment {
+            return Err(AFT34Error::Custom(String::from("InvalidSeed")));
+        }
+
+        let starting_offset = Internal::_derive_starting_offset(self, &seed);
+        self.data::<Data>().starting_offset = Some(starting_offset);
+        Internal::_emit_reveal_event(self, seed, starting_offset);
+        Ok(())
+    }
+
+    fn _derive_starting_offset(&self, seed: &[u8; 32]) -> u64 {
+        let mut input = [0u8; 64];
+        input[..32].copy_from_slice(&self.data::<Data>().provenance.unwrap_or_default());
+        input[32..].copy_from_slice(seed);
+
+        let mut output = [0u8; 32];
+        ink::env::hash_bytes::<Keccak256>(&input, &mut output);
+
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&output[..8]);
+        u64::from_be_bytes(bytes) % self.data::<Data>().collection_size
+    }
+
+    fn _metadata_id(&self, id: &Id) -> Result<Option<u64>, AFT34Error> {
+        let starting_offset = match self.data::<Data>().starting_offset {
+            Some(starting_offset) => starting_offset,
+            None => return Ok(None),
+        };
+
+        let index = id.as_u128().unwrap_or(0);
+        let collection_size = self.data::<Data>().collection_size as u128;
+        if index == 0 || index > collection_size {
+            return Err(AFT34Error::Custom(String::from("IdOutOfCollection")));
+        }
+
+        Ok(Some(
+            ((index - 1 + starting_offset as u128) % collection_size + 1) as u64,
+        ))
+    }
+}
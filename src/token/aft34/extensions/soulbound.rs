@@ -0,0 +1,160 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft34,
+    aft34::extensions::soulbound,
+    traits::aft34::{extensions::soulbound::*, *},
+};
+pub use aft34::{
+    AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
+};
+pub use soulbound::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{AccountId, Storage, String},
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    /// Whether tokens are transferable unless locked individually, tokens being locked by default.
+    pub unlocked_by_default: bool,
+    /// Per-token lock state, taking precedence over `unlocked_by_default`.
+    pub locks: Mapping<Id, bool>,
+    pub burn_allowed: bool,
+}
+
+pub trait AFT34SoulboundImpl: Internal + aft34::Internal {
+    fn locked(&self, id: Id) -> Result<bool, AFT34Error> {
+        self._check_token_exists(&id)?;
+        Ok(Internal::_is_locked(self, &id))
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when a token gets locked.
+    fn _emit_locked_event(&self, id: Id);
+
+    /// Event is emitted when a token gets unlocked.
+    fn _emit_unlocked_event(&self, id: Id);
+
+    fn _is_locked(&self, id: &Id) -> bool;
+
+    /// Locks or unlocks every token that has no lock state of its own.
+    ///
+    /// Tokens are locked by default.
+    fn _set_locked_by_default(&mut self, locked: bool);
+
+    /// Binds `id` to its owner.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    fn _lock(&mut self, id: Id) -> Result<(), AFT34Error>;
+
+    /// Makes `id` transferable again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    fn _unlock(&mut self, id: Id) -> Result<(), AFT34Error>;
+
+    /// Allows the owners of locked tokens to burn them.
+    fn _set_burn_allowed(&mut self, burn_allowed: bool);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + aft34::Internal {
+    fn _emit_locked_event(&self, _id: Id) {}
+
+    fn _emit_unlocked_event(&self, _id: Id) {}
+
+    fn _is_locked(&self, id: &Id) -> bool {
+        self.data::<Data>()
+            .locks
+            .get(id)
+            .unwrap_or(!self.data::<Data>().unlocked_by_default)
+    }
+
+    fn _set_locked_by_default(&mut self, locked: bool) {
+        self.data::<Data>().unlocked_by_default = !locked;
+    }
+
+    fn _lock(&mut self, id: Id) -> Result<(), AFT34Error> {
+        self._check_token_exists(&id)?;
+        if !Internal::_is_locked(self, &id) {
+            self.data::<Data>().locks.insert(&id, &true);
+            Internal::_emit_locked_event(self, id);
+        }
+        Ok(())
+    }
+
+    fn _unlock(&mut self, id: Id) -> Result<(), AFT34Error> {
+        self._check_token_exists(&id)?;
+        if Internal::_is_locked(self, &id) {
+            self.data::<Data>().locks.insert(&id, &false);
+            Internal::_emit_unlocked_event(self, id);
+        }
+        Ok(())
+    }
+
+    fn _set_burn_allowed(&mut self, burn_allowed: bool) {
+        self.data::<Data>().burn_allowed = burn_allowed;
+    }
+}
+
+/// Transfer hooks of [`aft34::Internal`] enforcing the locks, run before the default ones
+/// when the contract implements `AFT34Soulbound`.
+pub trait AFT34TransferImpl: Internal + Storage<Data> {
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), AFT34Error> {
+        if from.is_none() || !Internal::_is_locked(self, id) {
+            return Ok(());
+        }
+        if to.is_some() {
+            return Err(AFT34Error::Custom(String::from("TokenIsSoulbound")));
+        }
+        if !self.data::<Data>().burn_allowed {
+            return Err(AFT34Error::Custom(String::from("BurnNotAllowed")));
+        }
+        Ok(())
+    }
+
+    fn _after_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), AFT34Error> {
+        match (from, to) {
+            (None, Some(_)) if Internal::_is_locked(self, id) => {
+                Internal::_emit_locked_event(self, id.clone());
+            }
+            (Some(_), None) => self.data::<Data>().locks.remove(id),
+            _ => {}
+        }
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::aft34::all_ids;
 pub use crate::{
     aft34,
     aft34::extensions::uri_storage,
@@ -27,37 +28,38 @@ pub use crate::{
 pub use aft34::{
     AFT34Impl, BalancesManager as _, Internal as _, InternalImpl as _, Operator, Owner,
 };
+pub use uri_storage::Internal as _;
+use ink::prelude::{string::ToString, vec::Vec};
 use openbrush::storage::Mapping;
-use openbrush::traits::{AccountId, Storage};
+use openbrush::traits::{AccountId, Storage, String};
 
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
     pub base_uri: Option<URI>,
     pub token_uris: Mapping<Id, URI>,
+    pub uri_fallback: bool,
+    pub uri_suffix: Option<URI>,
+    /// URI of each frozen token as resolved when it was frozen.
+    pub frozen: Mapping<Id, Option<URI>>,
+    pub frozen_all: bool,
 }
 
-pub trait AFT34URIStorageImpl: aft34::Internal + Storage<Data> {
+pub trait AFT34URIStorageImpl: Internal + Storage<Data> {
     fn base_uri(&self) -> Option<URI> {
         self.data().base_uri.clone()
     }
     fn token_uri(&self, token_id: Id) -> Result<Option<URI>, AFT34Error> {
         aft34::Internal::_owner_of(self, &token_id).ok_or(AFT34Error::TokenNotExists)?;
-        let token_uri = self.data().token_uris.get(&token_id);
-        match token_uri {
-            None => Ok(None),
-            Some(uri) => {
-                let base_uri = self.data().base_uri.clone();
-
-                match base_uri {
-                    // If both are set, concatenate the baseURI and tokenURI.
-                    Some(base) => Ok(Some(base + &uri)),
-                    // If there is no base URI, return the token URI.
-                    None => Ok(Some(uri)),
-                }
-            }
+        match self.data().frozen.get(&token_id) {
+            Some(frozen_uri) => Ok(frozen_uri),
+            None => Ok(Internal::_resolve_token_uri(self, &token_id)),
         }
     }
+
+    fn is_metadata_frozen(&self, token_id: Id) -> bool {
+        Internal::_metadata_frozen(self, &token_id)
+    }
 }
 
 pub trait Internal: aft34::Internal {
@@ -65,39 +67,198 @@ pub trait Internal: aft34::Internal {
     fn _emit_attribute_set_event(&self, token_id: Id, token_uri: URI);
     /// Event is emitted when the base URI is updated.
     fn _emit_attribute_set_base_event(&self, base_uri: Option<URI>);
+    /// Event is emitted when the metadata of a token changes.
+    fn _emit_metadata_update_event(&self, token_id: Id);
+    /// Event is emitted when the metadata of a range of tokens changes.
+    fn _emit_batch_metadata_update_event(&self, from_token_id: Id, to_token_id: Id);
+    /// Event is emitted when the metadata of a token, or of all tokens if `None`, is frozen.
+    fn _emit_metadata_frozen_event(&self, token_id: Option<Id>);
 
     /// Sets `token_uri` as the tokenURI of `token_id`.
     ///
-    /// `token_id` must exist.
+    /// `token_id` must exist and its metadata must not be frozen.
     fn _set_token_uri(&mut self, token_id: Id, token_uri: URI) -> Result<(), AFT34Error>;
 
-    /// Sets `base_uri`.
-    fn _set_base_uri(&mut self, base_uri: Option<URI>);
+    /// Sets the tokenURI of each `(token_id, token_uri)` pair of `token_uris`.
+    ///
+    /// Every `token_id` must exist and its metadata must not be frozen.
+    ///
+    /// A single `BatchMetadataUpdate` event covering the ids of the batch is emitted.
+    fn _set_token_uri_batch(&mut self, token_uris: Vec<(Id, URI)>) -> Result<(), AFT34Error>;
+
+    /// Sets `base_uri`, emitting a `BatchMetadataUpdate` event for all the tokens.
+    ///
+    /// Fails with `MetadataFrozen` once all the metadata is frozen.
+    fn _set_base_uri(&mut self, base_uri: Option<URI>) -> Result<(), AFT34Error>;
+
+    /// Enables or disables the fallback of tokens without a tokenURI to
+    /// `base_uri + render(id) + suffix`.
+    ///
+    /// Fails with `MetadataFrozen` once all the metadata is frozen.
+    fn _set_uri_fallback(&mut self, enabled: bool) -> Result<(), AFT34Error>;
+
+    /// Sets the suffix appended to the URIs derived from the baseURI, e.g. `.json`.
+    ///
+    /// Fails with `MetadataFrozen` once all the metadata is frozen.
+    fn _set_uri_suffix(&mut self, uri_suffix: Option<URI>) -> Result<(), AFT34Error>;
+
+    /// Returns `true` if the metadata of `token_id` is frozen.
+    fn _metadata_frozen(&self, token_id: &Id) -> bool;
+
+    /// Returns the URI of `token_id` derived from the current tokenURI, baseURI and fallback settings.
+    fn _resolve_token_uri(&self, token_id: &Id) -> Option<URI>;
+
+    /// Permanently freezes the URI of `token_id`, `token_uri` returning the URI resolved at this point
+    /// whatever the later baseURI and fallback settings.
+    ///
+    /// Fails with `TokenNotExists` if `token_id` doesn't exist, or `MetadataFrozen` if it is already frozen.
+    fn _freeze_metadata(&mut self, token_id: Id) -> Result<(), AFT34Error>;
+
+    /// Permanently freezes the tokenURI of every token, the baseURI and the fallback settings.
+    ///
+    /// The attributes of `AFT34Metadata` have their own freeze state, see `metadata::Internal::_freeze_all`.
+    fn _freeze_all(&mut self);
+
+    /// Renders `token_id` as used in the URIs derived from the baseURI.
+    ///
+    /// Integer ids are rendered in decimal, bytes ids in lowercase hexadecimal with a `0x` prefix.
+    fn _render_id(&self, token_id: &Id) -> String;
 
     /// This override additionally checks to see if
     /// token-specific URI was set for the token, and if so, it deletes the token URI from
-    ///  the storage mapping.
+    ///  the storage mapping. The freeze of the token is lifted along with it.
     fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), AFT34Error>;
 }
 
 pub trait InternalImpl: Internal + Storage<Data> {
     fn _emit_attribute_set_event(&self, _token_id: Id, _token_uri: URI) {}
     fn _emit_attribute_set_base_event(&self, _base_uri: Option<URI>) {}
+    fn _emit_metadata_update_event(&self, _token_id: Id) {}
+    fn _emit_batch_metadata_update_event(&self, _from_token_id: Id, _to_token_id: Id) {}
+    fn _emit_metadata_frozen_event(&self, _token_id: Option<Id>) {}
 
     fn _set_token_uri(&mut self, token_id: Id, token_uri: URI) -> Result<(), AFT34Error> {
         aft34::Internal::_owner_of(self, &token_id).ok_or(AFT34Error::TokenNotExists)?;
+        if Internal::_metadata_frozen(self, &token_id) {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
         self.data().token_uris.insert(&token_id, &token_uri);
-        Internal::_emit_attribute_set_event(self, token_id, token_uri);
+        Internal::_emit_attribute_set_event(self, token_id.clone(), token_uri);
+        Internal::_emit_metadata_update_event(self, token_id);
+        Ok(())
+    }
+
+    fn _set_token_uri_batch(&mut self, token_uris: Vec<(Id, URI)>) -> Result<(), AFT34Error> {
+        for (token_id, _) in &token_uris {
+            aft34::Internal::_owner_of(self, token_id).ok_or(AFT34Error::TokenNotExists)?;
+            if Internal::_metadata_frozen(self, token_id) {
+                return Err(AFT34Error::MetadataFrozen);
+            }
+        }
+
+        let first = token_uris
+            .iter()
+            .map(|(token_id, _)| token_id)
+            .min()
+            .cloned();
+        let last = token_uris
+            .iter()
+            .map(|(token_id, _)| token_id)
+            .max()
+            .cloned();
+
+        for (token_id, token_uri) in token_uris {
+            self.data().token_uris.insert(&token_id, &token_uri);
+            Internal::_emit_attribute_set_event(self, token_id, token_uri);
+        }
+
+        if let (Some(first), Some(last)) = (first, last) {
+            Internal::_emit_batch_metadata_update_event(self, first, last);
+        }
+
         Ok(())
     }
 
-    fn _set_base_uri(&mut self, base_uri: Option<URI>) {
+    fn _set_base_uri(&mut self, base_uri: Option<URI>) -> Result<(), AFT34Error> {
+        if self.data().frozen_all {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
         self.data().base_uri = base_uri.clone();
-        Internal::_emit_attribute_set_base_event(self, base_uri)
+        Internal::_emit_attribute_set_base_event(self, base_uri);
+        let (first, last) = all_ids();
+        Internal::_emit_batch_metadata_update_event(self, first, last);
+        Ok(())
+    }
+
+    fn _set_uri_fallback(&mut self, enabled: bool) -> Result<(), AFT34Error> {
+        if self.data().frozen_all {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
+        self.data().uri_fallback = enabled;
+        let (first, last) = all_ids();
+        Internal::_emit_batch_metadata_update_event(self, first, last);
+        Ok(())
+    }
+
+    fn _set_uri_suffix(&mut self, uri_suffix: Option<URI>) -> Result<(), AFT34Error> {
+        if self.data().frozen_all {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
+        self.data().uri_suffix = uri_suffix;
+        let (first, last) = all_ids();
+        Internal::_emit_batch_metadata_update_event(self, first, last);
+        Ok(())
+    }
+
+    fn _metadata_frozen(&self, token_id: &Id) -> bool {
+        self.data().frozen_all || self.data().frozen.contains(token_id)
+    }
+
+    fn _resolve_token_uri(&self, token_id: &Id) -> Option<URI> {
+        let base_uri = self.data().base_uri.clone();
+        match self.data().token_uris.get(token_id) {
+            // If the fallback is enabled, derive the URI from the baseURI and the id.
+            None if self.data().uri_fallback => base_uri.map(|base| {
+                let id = Internal::_render_id(self, token_id);
+                let suffix = self.data().uri_suffix.clone().unwrap_or_default();
+                base + &id + &suffix
+            }),
+            None => None,
+            // If both are set, concatenate the baseURI and tokenURI.
+            Some(uri) => Some(base_uri.map_or(uri.clone(), |base| base + &uri)),
+        }
+    }
+
+    fn _freeze_metadata(&mut self, token_id: Id) -> Result<(), AFT34Error> {
+        aft34::Internal::_owner_of(self, &token_id).ok_or(AFT34Error::TokenNotExists)?;
+        if Internal::_metadata_frozen(self, &token_id) {
+            return Err(AFT34Error::MetadataFrozen);
+        }
+
+        let token_uri = Internal::_resolve_token_uri(self, &token_id);
+        self.data().frozen.insert(&token_id, &token_uri);
+        Internal::_emit_metadata_frozen_event(self, Some(token_id));
+        Ok(())
+    }
+
+    fn _freeze_all(&mut self) {
+        self.data().frozen_all = true;
+        Internal::_emit_metadata_frozen_event(self, None);
+        let (first, last) = all_ids();
+        Internal::_emit_batch_metadata_update_event(self, first, last);
+    }
+
+    fn _render_id(&self, token_id: &Id) -> String {
+        token_id.value().to_string()
     }
 
     fn _burn_from(&mut self, from: AccountId, id: Id) -> Result<(), AFT34Error> {
         self.data().token_uris.remove(&id);
+        self.data().frozen.remove(&id);
         aft34::Internal::_burn_from(self, from, id)
     }
 }
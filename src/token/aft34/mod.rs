@@ -19,6 +19,7 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::traits::types::Id;
 use openbrush::traits::AccountId;
 
 pub mod aft34;
@@ -27,11 +28,23 @@ pub use aft34::*;
 pub mod extensions {
     pub mod burnable;
     pub mod enumerable;
+    pub mod lazy_mint;
     pub mod metadata;
     pub mod mintable;
+    pub mod nestable;
+    pub mod onchain_metadata;
     pub mod payable_mint;
+    pub mod rentable;
+    pub mod reveal;
+    pub mod soulbound;
     pub mod uri_storage;
 }
 
 pub type Owner = AccountId;
 pub type Operator = AccountId;
+
+/// Range of ids used to signal a change of the metadata of every token, as EIP-4906 does with
+/// `0` to `type(uint256).max`.
+pub(crate) fn all_ids() -> (Id, Id) {
+    (Id::U8(0), Id::U128(u128::MAX))
+}
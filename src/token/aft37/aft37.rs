@@ -25,7 +25,7 @@ use core::result::Result;
 use ink::prelude::{vec, vec::Vec};
 use openbrush::{
     storage::{Mapping, TypeGuard},
-    traits::{AccountId, Balance, DefaultEnv, Storage},
+    traits::{AccountId, Balance, DefaultEnv, Storage, String, Timestamp},
 };
 
 #[derive(Default, Debug)]
@@ -33,7 +33,11 @@ use openbrush::{
 pub struct Data {
     pub balances: Mapping<(AccountId, Option<Id>), Balance, BalancesKey>,
     pub supply: Mapping<Option<Id>, Balance, SupplyKey>,
+    /// Allowances written before expiries existed, read as never expiring and dropped on the next write.
     pub operator_approvals: Mapping<(AccountId, AccountId, Option<Id>), Balance, ApprovalsKey>,
+    /// Allowances with an optional expiry, `Balance::MAX` for approvals of all tokens.
+    pub operator_approvals_with_expiry:
+        Mapping<(AccountId, AccountId, Option<Id>), (Balance, Option<Timestamp>), ApprovalsKey>,
 }
 
 pub struct BalancesKey;
@@ -70,13 +74,33 @@ pub trait AFT37Impl: Internal + BalancesManager + Sized {
         }
     }
 
+    fn approval_expiry(
+        &self,
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+    ) -> Option<Option<Timestamp>> {
+        self._approval(&owner, &operator, &id.as_ref())
+            .map(|(_, expires)| expires)
+    }
+
     fn approve(
         &mut self,
         operator: AccountId,
         id: Option<Id>,
         value: Balance,
     ) -> Result<(), AFT37Error> {
-        self._approve_for(operator, id, value)
+        self._approve_for(operator, id, value, None)
+    }
+
+    fn approve_until(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        value: Balance,
+        expires: Timestamp,
+    ) -> Result<(), AFT37Error> {
+        self._approve_for(operator, id, value, Some(expires))
     }
 
     fn transfer(
@@ -124,6 +148,7 @@ pub trait Internal {
         _operator: AccountId,
         _id: Option<Id>,
         value: Balance,
+        expires: Option<Timestamp>,
     );
 
     /// Checks if `id` exists.
@@ -133,13 +158,22 @@ pub trait Internal {
     /// Returns with `TokenNotExists` error if `id` token does not exist.
     fn _token_exists(&self, id: &Id) -> Result<(), AFT37Error>;
 
+    /// Returns the rules the ids minted in the collection must follow, any id being accepted by default.
+    ///
+    /// Override it with `#[overrider(aft37::Internal)]` to restrict the ids of the collection.
+    fn _id_policy(&self) -> IdPolicy;
+
     /// Creates `amount` tokens of token type `id` to `to`.
     ///
     /// On success a `TransferSingle` event is emitted if length of `ids_amounts` is 1, otherwise `TransferBatch` event.
     ///
+    /// The ids are normalized by the id policy first, so the tokens may be minted under another variant.
+    ///
     /// # Errors
     ///
     /// Returns with `TransferToNonSetAddress` error if `to` non set account.
+    ///
+    /// Returns with `InvalidId` error if one of the ids doesn't follow the id policy.
     fn _mint_to(
         &mut self,
         to: AccountId,
@@ -177,11 +211,22 @@ pub trait Internal {
         id: &Option<&Id>,
     ) -> Balance;
 
+    /// Returns the live allowance of `operator` for `id` and its expiry, if any.
+    fn _approval(
+        &self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<&Id>,
+    ) -> Option<(Balance, Option<Timestamp>)>;
+
+    /// Sets the allowance of `operator` over the caller's `id` tokens,
+    /// lapsing at `expires` if it is set.
     fn _approve_for(
         &mut self,
         operator: AccountId,
         id: Option<Id>,
         value: Balance,
+        expires: Option<Timestamp>,
     ) -> Result<(), AFT37Error>;
 
     fn _decrease_allowance(
@@ -240,6 +285,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         _operator: AccountId,
         _id: Option<Id>,
         _value: Balance,
+        _expires: Option<Timestamp>,
     ) {
     }
 
@@ -250,6 +296,10 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         Ok(())
     }
 
+    fn _id_policy(&self) -> IdPolicy {
+        IdPolicy::default()
+    }
+
     fn _mint_to(
         &mut self,
         to: AccountId,
@@ -259,6 +309,11 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
             return Ok(());
         }
 
+        let policy = Internal::_id_policy(self);
+        for (id, _) in ids_amounts.iter_mut() {
+            *id = policy.normalize(id.clone())?;
+        }
+
         Internal::_before_token_transfer(self, None, Some(&to), &ids_amounts)?;
 
         for (id, amount) in &ids_amounts {
@@ -329,12 +384,24 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
     }
 
     fn _get_allowance(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>) -> Balance {
-        match self._get_operator_approvals(owner, operator, &None) {
-            None => self
-                ._get_operator_approvals(owner, operator, id)
-                .unwrap_or(0),
-            _ => Balance::MAX,
-        }
+        Internal::_approval(self, owner, operator, id).map_or(0, |(value, _)| value)
+    }
+
+    fn _approval(
+        &self,
+        owner: &AccountId,
+        operator: &AccountId,
+        id: &Option<&Id>,
+    ) -> Option<(Balance, Option<Timestamp>)> {
+        let now = Self::env().block_timestamp();
+        let live = |(_, expires): &(Balance, Option<Timestamp>)| is_live(expires, now);
+
+        self._get_operator_approvals(owner, operator, &None)
+            .filter(live)
+            .or_else(|| {
+                self._get_operator_approvals(owner, operator, id)
+                    .filter(live)
+            })
     }
 
     fn _approve_for(
@@ -342,6 +409,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         operator: AccountId,
         id: Option<Id>,
         value: Balance,
+        expires: Option<Timestamp>,
     ) -> Result<(), AFT37Error> {
         let caller = Self::env().caller();
 
@@ -349,19 +417,23 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
             return Err(AFT37Error::SelfApprove);
         }
 
+        if value != 0 && !is_live(&expires, Self::env().block_timestamp()) {
+            return Err(AFT37Error::Custom(String::from("InvalidExpiry")));
+        }
+
         if let Some(id) = &id {
             if value == 0 {
                 self._remove_operator_approvals(&caller, &operator, &Some(id));
             } else {
-                self._insert_operator_approvals(&caller, &operator, &Some(id), &value);
+                self._insert_operator_approvals(&caller, &operator, &Some(id), &value, &expires);
             }
         } else if value == 0 {
             self._remove_operator_approvals(&caller, &operator, &None);
         } else {
-            self._insert_operator_approvals(&caller, &operator, &None, &Balance::MAX);
+            self._insert_operator_approvals(&caller, &operator, &None, &Balance::MAX, &expires);
         }
 
-        Internal::_emit_approval_event(self, caller, operator, id, value);
+        Internal::_emit_approval_event(self, caller, operator, id, value, expires);
 
         Ok(())
     }
@@ -377,7 +449,8 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
             return Ok(());
         }
 
-        let initial_allowance = Internal::_get_allowance(self, owner, operator, &Some(id));
+        let (initial_allowance, expires) =
+            Internal::_approval(self, owner, operator, &Some(id)).unwrap_or((0, None));
 
         if initial_allowance == Balance::MAX {
             return Ok(());
@@ -387,7 +460,13 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
             return Err(AFT37Error::InsufficientBalance);
         }
 
-        self._insert_operator_approvals(owner, operator, &Some(id), &(initial_allowance - value));
+        self._insert_operator_approvals(
+            owner,
+            operator,
+            &Some(id),
+            &(initial_allowance - value),
+            &expires,
+        );
 
         Ok(())
     }
@@ -451,6 +530,7 @@ pub trait BalancesManager {
         operator: &AccountId,
         id: &Option<&Id>,
         amount: &Balance,
+        expires: &Option<Timestamp>,
     );
 
     fn _get_operator_approvals(
@@ -458,7 +538,7 @@ pub trait BalancesManager {
         owner: &AccountId,
         operator: &AccountId,
         id: &Option<&Id>,
-    ) -> Option<Balance>;
+    ) -> Option<(Balance, Option<Timestamp>)>;
 
     fn _remove_operator_approvals(&self, owner: &AccountId, operator: &AccountId, id: &Option<&Id>);
 }
@@ -564,10 +644,13 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
         operator: &AccountId,
         id: &Option<&Id>,
         amount: &Balance,
+        expires: &Option<Timestamp>,
     ) {
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
         self.data()
-            .operator_approvals
-            .insert(&(owner, operator, id), amount);
+            .operator_approvals_with_expiry
+            .insert(&key, &(*amount, *expires));
     }
 
     fn _get_operator_approvals(
@@ -575,8 +658,17 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
         owner: &AccountId,
         operator: &AccountId,
         id: &Option<&Id>,
-    ) -> Option<Balance> {
-        self.data().operator_approvals.get(&(owner, operator, id))
+    ) -> Option<(Balance, Option<Timestamp>)> {
+        let key = (owner, operator, id);
+        self.data()
+            .operator_approvals_with_expiry
+            .get(&key)
+            .or_else(|| {
+                self.data()
+                    .operator_approvals
+                    .get(&key)
+                    .map(|amount| (amount, None))
+            })
     }
 
     fn _remove_operator_approvals(
@@ -585,8 +677,13 @@ pub trait BalancesManagerImpl: BalancesManager + Storage<Data> {
         operator: &AccountId,
         id: &Option<&Id>,
     ) {
-        self.data()
-            .operator_approvals
-            .remove(&(owner, operator, id));
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
+        self.data().operator_approvals_with_expiry.remove(&key);
     }
 }
+
+/// Whether an approval with `expires` is still valid at `now`.
+fn is_live(expires: &Option<Timestamp>, now: Timestamp) -> bool {
+    expires.map_or(true, |expires| now < expires)
+}
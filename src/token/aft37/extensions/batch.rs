@@ -28,10 +28,10 @@ pub use aft37::{
     AFT37Impl, BalancesManager as _, BalancesManagerImpl as _, Internal as _, InternalImpl as _,
 };
 pub use batch::Internal as _;
-use ink::prelude::vec::Vec;
-use openbrush::traits::{AccountId, Balance, Storage};
+use ink::prelude::{vec, vec::Vec};
+use openbrush::traits::{AccountId, Balance, Storage, String};
 
-pub trait AFT37BatchImpl: Internal + Storage<aft37::Data> {
+pub trait AFT37BatchImpl: Internal + Storage<aft37::Data> + aft37::BalancesManager {
     fn batch_transfer(
         &mut self,
         to: AccountId,
@@ -50,9 +50,54 @@ pub trait AFT37BatchImpl: Internal + Storage<aft37::Data> {
     ) -> Result<(), AFT37Error> {
         self._batch_transfer_from(from, to, ids_amounts, data)
     }
+
+    fn batch_transfer_multi(
+        &mut self,
+        transfers: Vec<(AccountId, Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), AFT37Error> {
+        self._batch_transfer_multi_from(Self::env().caller(), transfers, data)
+    }
+
+    fn batch_transfer_multi_from(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), AFT37Error> {
+        self._batch_transfer_multi_from(from, transfers, data)
+    }
+
+    fn balance_of_batch(
+        &self,
+        owners_ids: Vec<(AccountId, Option<Id>)>,
+    ) -> Result<Vec<Balance>, AFT37Error> {
+        if owners_ids.len() > self._max_batch_size() as usize {
+            return Err(AFT37Error::Custom(String::from("BatchTooLarge")));
+        }
+
+        Ok(owners_ids
+            .iter()
+            .map(|(owner, id)| self._balance_of(owner, &id.as_ref()))
+            .collect())
+    }
+
+    fn total_supply_batch(&self, ids: Vec<Option<Id>>) -> Result<Vec<Balance>, AFT37Error> {
+        if ids.len() > self._max_batch_size() as usize {
+            return Err(AFT37Error::Custom(String::from("BatchTooLarge")));
+        }
+
+        Ok(ids
+            .iter()
+            .map(|id| self._total_supply(&id.as_ref()))
+            .collect())
+    }
 }
 
 pub trait Internal {
+    /// Maximum amount of entries accepted by a single batch query or multi-recipient transfer.
+    fn _max_batch_size(&self) -> u32;
+
     fn _batch_transfer_from(
         &mut self,
         from: AccountId,
@@ -60,11 +105,27 @@ pub trait Internal {
         ids_amounts: Vec<(Id, Balance)>,
         data: Vec<u8>,
     ) -> Result<(), AFT37Error>;
+
+    /// Transfers each `(to, id, amount)` entry of `transfers` from `from`, grouped by recipient.
+    ///
+    /// Allowances are checked for the total amount of each id before anything is transferred.
+    ///
+    /// Returns `Custom("BatchTooLarge")` error if `transfers` exceeds the maximum batch size.
+    fn _batch_transfer_multi_from(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), AFT37Error>;
 }
 
 pub trait InternalImpl:
     Internal + aft37::Internal + Storage<aft37::Data> + aft37::BalancesManager
 {
+    fn _max_batch_size(&self) -> u32 {
+        100
+    }
+
     fn _batch_transfer_from(
         &mut self,
         from: AccountId,
@@ -98,4 +159,59 @@ pub trait InternalImpl:
 
         Ok(())
     }
+
+    fn _batch_transfer_multi_from(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Id, Balance)>,
+        _: Vec<u8>,
+    ) -> Result<(), AFT37Error> {
+        if transfers.len() > Internal::_max_batch_size(self) as usize {
+            return Err(AFT37Error::Custom(String::from("BatchTooLarge")));
+        }
+
+        let operator = Self::env().caller();
+
+        let mut totals: Vec<(Id, Balance)> = Vec::new();
+        let mut batches: Vec<(AccountId, Vec<(Id, Balance)>)> = Vec::new();
+
+        for (to, id, value) in transfers {
+            match totals.iter_mut().find(|(total_id, _)| *total_id == id) {
+                Some((_, total)) => {
+                    *total = total
+                        .checked_add(value)
+                        .ok_or(AFT37Error::Custom(String::from("Overflow")))?
+                }
+                None => totals.push((id.clone(), value)),
+            }
+
+            match batches.iter_mut().find(|(recipient, _)| *recipient == to) {
+                Some((_, ids_amounts)) => ids_amounts.push((id, value)),
+                None => batches.push((to, vec![(id, value)])),
+            }
+        }
+
+        for (id, total) in &totals {
+            if from != operator && &self._get_allowance(&from, &operator, &Some(id)) < total {
+                return Err(AFT37Error::NotAllowed);
+            }
+        }
+
+        for (to, ids_amounts) in batches {
+            self._before_token_transfer(Some(&from), Some(&to), &ids_amounts)?;
+
+            for (id, value) in &ids_amounts {
+                self._decrease_allowance(&from, &operator, id, *value)?;
+
+                self._decrease_balance(&from, id, value, false)?;
+                self._increase_balance(&to, id, value, false)?;
+            }
+
+            self._after_token_transfer(Some(&from), Some(&to), &ids_amounts)?;
+
+            self._emit_transfer_batch_event(Some(from), Some(to), ids_amounts);
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,133 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft37,
+    aft37::extensions::capped,
+    traits::aft37::{extensions::capped::*, *},
+};
+pub use aft37::{
+    AFT37Impl, BalancesManager as _, BalancesManagerImpl as _, Internal as _, InternalImpl as _,
+};
+pub use capped::Internal as _;
+use ink::prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{AccountId, Balance, Storage, String},
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub max_supply: Mapping<Id, Balance>,
+}
+
+pub trait AFT37CappedImpl: Internal {
+    fn max_supply(&self, id: Id) -> Option<Balance> {
+        self._max_supply(&id)
+    }
+}
+
+pub trait Internal {
+    /// Returns the maximum supply of token type `id`, `None` if it is not capped.
+    fn _max_supply(&self, id: &Id) -> Option<Balance>;
+
+    /// Caps the supply of token type `id` to `cap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("Cap must be above 0")` error if `cap` is 0.
+    ///
+    /// Returns `MaxSupplyExceeded` error if more than `cap` tokens of `id` are already minted.
+    fn _set_max_supply(&mut self, id: Id, cap: Balance) -> Result<(), AFT37Error>;
+
+    /// Returns `true` if minting `amount` more tokens of `id` would exceed its maximum supply.
+    fn _is_max_supply_exceeded(&self, id: &Id, amount: Balance) -> bool;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + aft37::BalancesManager {
+    fn _max_supply(&self, id: &Id) -> Option<Balance> {
+        self.data().max_supply.get(id)
+    }
+
+    fn _set_max_supply(&mut self, id: Id, cap: Balance) -> Result<(), AFT37Error> {
+        if cap == 0 {
+            return Err(AFT37Error::Custom(String::from("Cap must be above 0")));
+        }
+        if self._total_supply(&Some(&id)) > cap {
+            return Err(AFT37Error::MaxSupplyExceeded);
+        }
+        self.data().max_supply.insert(&id, &cap);
+        Ok(())
+    }
+
+    fn _is_max_supply_exceeded(&self, id: &Id, amount: Balance) -> bool {
+        match Internal::_max_supply(self, id) {
+            Some(cap) => self
+                ._total_supply(&Some(id))
+                .checked_add(amount)
+                .map_or(true, |supply| supply > cap),
+            None => false,
+        }
+    }
+}
+
+/// Transfer hooks of [`aft37::Internal`] enforcing the maximum supplies, run before the default ones
+/// when the contract implements `AFT37Capped`.
+pub trait AFT37TransferImpl: Internal {
+    fn _before_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        ids: &[(Id, Balance)],
+    ) -> Result<(), AFT37Error> {
+        if from.is_some() {
+            return Ok(());
+        }
+
+        // The same id may appear several times in a batch, so the amounts are summed per id.
+        let mut minted: Vec<(&Id, Balance)> = Vec::new();
+        for (id, amount) in ids {
+            match minted.iter_mut().find(|(minted_id, _)| *minted_id == id) {
+                Some((_, total)) => *total = total.saturating_add(*amount),
+                None => minted.push((id, *amount)),
+            }
+        }
+
+        if minted
+            .into_iter()
+            .any(|(id, amount)| Internal::_is_max_supply_exceeded(self, id, amount))
+        {
+            return Err(AFT37Error::MaxSupplyExceeded);
+        }
+
+        Ok(())
+    }
+
+    fn _after_token_transfer(
+        &mut self,
+        _from: Option<&AccountId>,
+        _to: Option<&AccountId>,
+        _ids: &[(Id, Balance)],
+    ) -> Result<(), AFT37Error> {
+        Ok(())
+    }
+}
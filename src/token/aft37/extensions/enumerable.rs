@@ -26,18 +26,24 @@ pub use crate::{
     traits::aft37::{extensions::enumerable::*, *},
 };
 pub use aft37::{AFT37Impl, BalancesManager as _, Internal as _, InternalImpl as _};
+pub use enumerable::Internal as _;
+use ink::prelude::vec::Vec;
 use openbrush::{
     storage::{Mapping, MultiMapping, TypeGuard},
-    traits::{AccountId, Balance, Storage},
+    traits::{AccountId, Balance, Storage, Timestamp},
 };
 
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
     pub enumerable: MultiMapping<Option<AccountId>, Id, EnumerableKey>,
+    pub holders: MultiMapping<Id, AccountId>,
     pub balances: Mapping<(AccountId, Id), Balance, BalancesKey>,
     pub supply: Mapping<Id, Balance>,
+    /// Allowances written before expiries existed, read as never expiring and dropped on the next write.
     pub operator_approvals: Mapping<(AccountId, AccountId, Option<Id>), Balance, ApprovalsKey>,
+    pub operator_approvals_with_expiry:
+        Mapping<(AccountId, AccountId, Option<Id>), (Balance, Option<Timestamp>), ApprovalsKey>,
 }
 
 pub struct EnumerableKey;
@@ -88,6 +94,7 @@ pub trait BalancesManagerImpl: Storage<Data> + aft37::BalancesManager {
 
         if balance_before == 0 {
             self.data().enumerable.insert(&Some(owner), id);
+            self.data().holders.insert(id, owner);
         }
 
         if mint {
@@ -124,6 +131,7 @@ pub trait BalancesManagerImpl: Storage<Data> + aft37::BalancesManager {
 
         if balance_after == 0 {
             self.data().enumerable.remove_value(&Some(owner), id);
+            self.data().holders.remove_value(id, owner);
         }
 
         if burn {
@@ -145,10 +153,13 @@ pub trait BalancesManagerImpl: Storage<Data> + aft37::BalancesManager {
         operator: &AccountId,
         id: &Option<&Id>,
         amount: &Balance,
+        expires: &Option<Timestamp>,
     ) {
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
         self.data()
-            .operator_approvals
-            .insert(&(owner, operator, id), amount);
+            .operator_approvals_with_expiry
+            .insert(&key, &(*amount, *expires));
     }
 
     fn _get_operator_approvals(
@@ -156,8 +167,17 @@ pub trait BalancesManagerImpl: Storage<Data> + aft37::BalancesManager {
         owner: &AccountId,
         operator: &AccountId,
         id: &Option<&Id>,
-    ) -> Option<Balance> {
-        self.data().operator_approvals.get(&(owner, operator, id))
+    ) -> Option<(Balance, Option<Timestamp>)> {
+        let key = (owner, operator, id);
+        self.data()
+            .operator_approvals_with_expiry
+            .get(&key)
+            .or_else(|| {
+                self.data()
+                    .operator_approvals
+                    .get(&key)
+                    .map(|amount| (amount, None))
+            })
     }
 
     fn _remove_operator_approvals(
@@ -166,13 +186,13 @@ pub trait BalancesManagerImpl: Storage<Data> + aft37::BalancesManager {
         operator: &AccountId,
         id: &Option<&Id>,
     ) {
-        self.data()
-            .operator_approvals
-            .remove(&(owner, operator, id));
+        let key = (owner, operator, id);
+        self.data().operator_approvals.remove(&key);
+        self.data().operator_approvals_with_expiry.remove(&key);
     }
 }
 
-pub trait AFT37EnumerableImpl: Storage<Data> {
+pub trait AFT37EnumerableImpl: Internal + Storage<Data> {
     fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Option<Id> {
         self.data().enumerable.get_value(&Some(&owner), &index)
     }
@@ -180,4 +200,41 @@ pub trait AFT37EnumerableImpl: Storage<Data> {
     fn token_by_index(&self, index: u128) -> Option<Id> {
         self.data().enumerable.get_value(&None, &index)
     }
+
+    fn holder_by_index(&self, id: Id, index: u128) -> Option<AccountId> {
+        self.data().holders.get_value(&id, &index)
+    }
+
+    fn holder_count(&self, id: Id) -> u128 {
+        self.data().holders.count(&id)
+    }
+
+    fn holders_of(&self, id: Id, offset: u128, limit: u128) -> Vec<AccountId> {
+        Internal::_holders_page(self, &id, offset, limit)
+    }
+}
+
+pub trait Internal {
+    /// Maximum amount of accounts returned by a single page query.
+    fn _max_page_size(&self) -> u128;
+
+    /// Returns up to `limit` holders of `id` starting at index `offset`.
+    fn _holders_page(&self, id: &Id, offset: u128, limit: u128) -> Vec<AccountId>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _max_page_size(&self) -> u128 {
+        100
+    }
+
+    fn _holders_page(&self, id: &Id, offset: u128, limit: u128) -> Vec<AccountId> {
+        let limit = limit.min(Internal::_max_page_size(self));
+        let end = offset
+            .saturating_add(limit)
+            .min(self.data().holders.count(id));
+
+        (offset..end)
+            .filter_map(|index| self.data().holders.get_value(id, &index))
+            .collect()
+    }
 }
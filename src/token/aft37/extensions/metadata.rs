@@ -27,9 +27,10 @@ pub use crate::{
 pub use aft37::{
     AFT37Impl, BalancesManager as _, BalancesManagerImpl as _, Internal as _, InternalImpl as _,
 };
+use ink::prelude::vec::Vec;
 pub use metadata::Internal as _;
 use openbrush::{
-    storage::{Mapping, TypeGuard},
+    storage::{Mapping, MultiMapping, TypeGuard},
     traits::{Storage, String},
 };
 
@@ -37,6 +38,7 @@ use openbrush::{
 #[openbrush::storage_item]
 pub struct Data {
     pub attributes: Mapping<(Id, String), String, AttributesKey>,
+    pub attribute_keys: MultiMapping<Id, String>,
 }
 
 pub struct AttributesKey;
@@ -45,29 +47,84 @@ impl<'a> TypeGuard<'a> for AttributesKey {
     type Type = &'a (&'a Id, &'a String);
 }
 
-pub trait AFT37MetadataImpl: Storage<Data> {
+pub trait AFT37MetadataImpl: Internal + Storage<Data> {
     fn get_attribute(&self, id: Id, key: String) -> Option<String> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    fn get_attributes(&self, id: Id) -> Vec<(String, String)> {
+        Internal::_attribute_keys(self, &id)
+            .into_iter()
+            .filter_map(|key| Internal::_get_attribute(self, &id, &key).map(|value| (key, value)))
+            .collect()
+    }
+
+    fn attribute_keys(&self, id: Id) -> Vec<String> {
+        Internal::_attribute_keys(self, &id)
+    }
 }
 
 pub trait Internal {
     fn _emit_attribute_set_event(&self, _id: &Id, _key: &String, _data: &String);
 
+    fn _emit_attribute_removed_event(&self, _id: &Id, _key: &String);
+
     fn _set_attribute(&mut self, id: &Id, key: &String, data: &String) -> Result<(), AFT37Error>;
 
+    fn _set_attributes(
+        &mut self,
+        id: &Id,
+        attributes: &[(String, String)],
+    ) -> Result<(), AFT37Error>;
+
+    fn _remove_attribute(&mut self, id: &Id, key: &String) -> Result<(), AFT37Error>;
+
+    fn _attribute_keys(&self, id: &Id) -> Vec<String>;
+
     fn _get_attribute(&self, id: &Id, key: &String) -> Option<String>;
 }
 
 pub trait InternalImpl: Internal + Storage<Data> {
     fn _emit_attribute_set_event(&self, _id: &Id, _key: &String, _data: &String) {}
 
+    fn _emit_attribute_removed_event(&self, _id: &Id, _key: &String) {}
+
     fn _set_attribute(&mut self, id: &Id, key: &String, data: &String) -> Result<(), AFT37Error> {
+        if !self.data().attributes.contains(&(id, key)) {
+            self.data().attribute_keys.insert(id, key);
+        }
+
         self.data().attributes.insert(&(id, key), data);
         Internal::_emit_attribute_set_event(self, id, key, data);
         Ok(())
     }
 
+    fn _set_attributes(
+        &mut self,
+        id: &Id,
+        attributes: &[(String, String)],
+    ) -> Result<(), AFT37Error> {
+        for (key, data) in attributes {
+            Internal::_set_attribute(self, id, key, data)?;
+        }
+        Ok(())
+    }
+
+    fn _remove_attribute(&mut self, id: &Id, key: &String) -> Result<(), AFT37Error> {
+        if self.data().attributes.contains(&(id, key)) {
+            self.data().attributes.remove(&(id, key));
+            self.data().attribute_keys.remove_value(id, key);
+            Internal::_emit_attribute_removed_event(self, id, key);
+        }
+        Ok(())
+    }
+
+    fn _attribute_keys(&self, id: &Id) -> Vec<String> {
+        (0..self.data().attribute_keys.count(id))
+            .filter_map(|index| self.data().attribute_keys.get_value(id, &index))
+            .collect()
+    }
+
     fn _get_attribute(&self, id: &Id, key: &String) -> Option<String> {
         self.data().attributes.get(&(id, key))
     }
@@ -0,0 +1,247 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    aft37,
+    aft37::extensions::royalty,
+    traits::aft37::{extensions::royalty::*, *},
+};
+pub use aft37::{
+    AFT37Impl, BalancesManager as _, BalancesManagerImpl as _, Internal as _, InternalImpl as _,
+};
+pub use royalty::Internal as _;
+use ink::prelude::{vec, vec::Vec};
+use openbrush::{
+    storage::{Mapping, TypeGuard},
+    traits::{AccountId, Balance, Storage, String},
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub default_royalty: Option<RoyaltyInfo>,
+    pub token_royalties: Mapping<Id, RoyaltyInfo>,
+    pub royalty_splits: Mapping<Option<Id>, Vec<(AccountId, u16)>, SplitsKey>,
+}
+
+pub struct SplitsKey;
+
+impl<'a> TypeGuard<'a> for SplitsKey {
+    type Type = &'a Option<&'a Id>;
+}
+
+pub trait AFT37RoyaltyImpl: Internal {
+    fn royalty_info(&self, id: Id, sale_price: Balance) -> Option<(AccountId, Balance)> {
+        let royalty = Internal::_royalty(self, &id)?;
+        let amount = Internal::_royalty_amount(self, sale_price, royalty.royalty_fraction);
+        Some((royalty.receiver, amount))
+    }
+
+    fn royalty_split(&self, id: Id, sale_price: Balance) -> Vec<(AccountId, Balance)> {
+        let (receiver, amount) = match AFT37RoyaltyImpl::royalty_info(self, id.clone(), sale_price)
+        {
+            None => return Vec::new(),
+            Some(info) => info,
+        };
+
+        match Internal::_royalty_split(self, &id) {
+            None => vec![(receiver, amount)],
+            Some(shares) => {
+                let mut remaining = amount;
+                let mut split: Vec<(AccountId, Balance)> = shares
+                    .into_iter()
+                    .map(|(recipient, share)| {
+                        let part = Internal::_royalty_amount(self, amount, share);
+                        remaining -= part;
+                        (recipient, part)
+                    })
+                    .collect();
+
+                // Rounding leftovers go to the first recipient so that the split adds up to `amount`.
+                if let Some((_, part)) = split.first_mut() {
+                    *part += remaining;
+                }
+                split
+            }
+        }
+    }
+}
+
+pub trait Internal {
+    /// Event is emitted when a royalty is set or removed.
+    ///
+    /// `id` is `None` for the collection-wide default royalty.
+    fn _emit_royalty_set_event(&self, id: Option<Id>, royalty: Option<RoyaltyInfo>);
+
+    /// Event is emitted when a royalty split is set or removed.
+    ///
+    /// `id` is `None` for the collection-wide default split.
+    fn _emit_royalty_split_set_event(&self, id: Option<Id>, shares: Vec<(AccountId, u16)>);
+
+    /// Denominator used to interpret royalty fractions and split shares.
+    fn _fee_denominator(&self) -> u16;
+
+    /// Returns the royalty of `id`, or the default royalty if none is set for `id`.
+    fn _royalty(&self, id: &Id) -> Option<RoyaltyInfo>;
+
+    /// Returns the split applying to the royalty of `id`.
+    ///
+    /// Tokens with their own royalty only use their own split, tokens using the default
+    /// royalty use the default split.
+    fn _royalty_split(&self, id: &Id) -> Option<Vec<(AccountId, u16)>>;
+
+    /// Returns the part of `amount` represented by `fraction` over the fee denominator.
+    fn _royalty_amount(&self, amount: Balance, fraction: u16) -> Balance;
+
+    /// Sets the royalty used by every token that has no royalty of its own.
+    fn _set_default_royalty(
+        &mut self,
+        receiver: AccountId,
+        royalty_fraction: u16,
+    ) -> Result<(), AFT37Error>;
+
+    /// Removes the default royalty.
+    fn _delete_default_royalty(&mut self);
+
+    /// Sets the royalty of `id`, overriding the default royalty.
+    fn _set_token_royalty(
+        &mut self,
+        id: Id,
+        receiver: AccountId,
+        royalty_fraction: u16,
+    ) -> Result<(), AFT37Error>;
+
+    /// Removes the royalty and the split of `id`, so that it uses the default ones again.
+    fn _reset_token_royalty(&mut self, id: Id);
+
+    /// Splits the royalty of `id` (or the default royalty if `id` is `None`) between `shares`.
+    ///
+    /// Shares are expressed over the fee denominator and must add up to it.
+    /// An empty `shares` removes the split.
+    fn _set_royalty_split(
+        &mut self,
+        id: Option<Id>,
+        shares: Vec<(AccountId, u16)>,
+    ) -> Result<(), AFT37Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_royalty_set_event(&self, _id: Option<Id>, _royalty: Option<RoyaltyInfo>) {}
+
+    fn _emit_royalty_split_set_event(&self, _id: Option<Id>, _shares: Vec<(AccountId, u16)>) {}
+
+    fn _fee_denominator(&self) -> u16 {
+        10_000
+    }
+
+    fn _royalty(&self, id: &Id) -> Option<RoyaltyInfo> {
+        self.data()
+            .token_royalties
+            .get(id)
+            .or_else(|| self.data().default_royalty.clone())
+    }
+
+    fn _royalty_split(&self, id: &Id) -> Option<Vec<(AccountId, u16)>> {
+        match self.data().token_royalties.get(id) {
+            Some(_) => self.data().royalty_splits.get(&Some(id)),
+            None => self.data().royalty_splits.get(&None),
+        }
+    }
+
+    fn _royalty_amount(&self, amount: Balance, fraction: u16) -> Balance {
+        let denominator = Internal::_fee_denominator(self) as Balance;
+        let fraction = fraction as Balance;
+
+        // Split the computation to avoid overflowing on large amounts.
+        amount / denominator * fraction + amount % denominator * fraction / denominator
+    }
+
+    fn _set_default_royalty(
+        &mut self,
+        receiver: AccountId,
+        royalty_fraction: u16,
+    ) -> Result<(), AFT37Error> {
+        if royalty_fraction > Internal::_fee_denominator(self) {
+            return Err(AFT37Error::Custom(String::from("RoyaltyTooHigh")));
+        }
+
+        let royalty = RoyaltyInfo {
+            receiver,
+            royalty_fraction,
+        };
+        self.data().default_royalty = Some(royalty.clone());
+        Internal::_emit_royalty_set_event(self, None, Some(royalty));
+        Ok(())
+    }
+
+    fn _delete_default_royalty(&mut self) {
+        self.data().default_royalty = None;
+        Internal::_emit_royalty_set_event(self, None, None);
+    }
+
+    fn _set_token_royalty(
+        &mut self,
+        id: Id,
+        receiver: AccountId,
+        royalty_fraction: u16,
+    ) -> Result<(), AFT37Error> {
+        if royalty_fraction > Internal::_fee_denominator(self) {
+            return Err(AFT37Error::Custom(String::from("RoyaltyTooHigh")));
+        }
+
+        let royalty = RoyaltyInfo {
+            receiver,
+            royalty_fraction,
+        };
+        self.data().token_royalties.insert(&id, &royalty);
+        Internal::_emit_royalty_set_event(self, Some(id), Some(royalty));
+        Ok(())
+    }
+
+    fn _reset_token_royalty(&mut self, id: Id) {
+        self.data().token_royalties.remove(&id);
+        self.data().royalty_splits.remove(&Some(&id));
+        Internal::_emit_royalty_set_event(self, Some(id), None);
+    }
+
+    fn _set_royalty_split(
+        &mut self,
+        id: Option<Id>,
+        shares: Vec<(AccountId, u16)>,
+    ) -> Result<(), AFT37Error> {
+        if shares.is_empty() {
+            self.data().royalty_splits.remove(&id.as_ref());
+        } else {
+            let total: u32 = shares.iter().map(|(_, share)| *share as u32).sum();
+
+            if shares.iter().any(|(_, share)| *share == 0)
+                || total != Internal::_fee_denominator(self) as u32
+            {
+                return Err(AFT37Error::Custom(String::from("InvalidRoyaltySplit")));
+            }
+
+            self.data().royalty_splits.insert(&id.as_ref(), &shares);
+        }
+
+        Internal::_emit_royalty_split_set_event(self, id, shares);
+        Ok(())
+    }
+}
@@ -25,8 +25,9 @@ pub use crate::{
     traits::aft37::{extensions::uri_storage::*, *},
 };
 pub use aft37::Internal as _;
-use ink::prelude::vec::Vec;
-use openbrush::traits::{AccountId, Storage};
+pub use uri_storage::Internal as _;
+use ink::prelude::{string::ToString, vec::Vec};
+use openbrush::traits::{AccountId, Storage, String};
 use openbrush::{storage::Mapping, traits::Balance};
 
 #[derive(Default, Debug)]
@@ -36,24 +37,38 @@ pub struct Data {
     pub token_uris: Mapping<Id, URI>,
 }
 
-pub trait AFT37URIStorageImpl: aft37::Internal + Storage<Data> {
+pub trait AFT37URIStorageImpl: Internal + Storage<Data> {
     fn base_uri(&self) -> Option<URI> {
         self.data().base_uri.clone()
     }
 
     fn token_uri(&self, token_id: Id) -> Result<Option<URI>, AFT37Error> {
+        let base_uri = self.data().base_uri.clone();
+
         match self.data().token_uris.get(&token_id) {
-            None => Err(AFT37Error::TokenNotExists)?,
             Some(uri) => {
-                let base_uri = self.data().base_uri.clone();
-
                 match base_uri {
+                    // A templated base URI is not a prefix, the token URI is returned as is.
+                    Some(base) if base.contains(ID_PLACEHOLDER) => Ok(Some(uri)),
                     // If both are set, concatenate the baseURI and tokenURI.
                     Some(base) => Ok(Some(base + &uri)),
                     // If there is no base URI, return the token URI.
                     None => Ok(Some(uri)),
                 }
             }
+            None => {
+                match base_uri {
+                    // If the base URI is templated, substitute the id of an existing token.
+                    Some(base) if base.contains(ID_PLACEHOLDER) => {
+                        aft37::Internal::_token_exists(self, &token_id)?;
+                        Ok(Some(base.replace(
+                            ID_PLACEHOLDER,
+                            &Internal::_render_id(self, &token_id),
+                        )))
+                    }
+                    _ => Err(AFT37Error::TokenNotExists),
+                }
+            }
         }
     }
 }
@@ -64,6 +79,12 @@ pub trait Internal: aft37::Internal {
     /// Event is emitted when the base URI is updated.
     fn _emit_attribute_set_base_event(&self, base_uri: Option<URI>);
 
+    /// Renders `token_id` as substituted for the `{id}` placeholder of a templated base URI.
+    ///
+    /// Integer ids are rendered in decimal, bytes ids in lowercase hexadecimal with a `0x` prefix,
+    /// as the token URIs of AFT34.
+    fn _render_id(&self, token_id: &Id) -> String;
+
     /// Sets `token_uri` as the tokenURI of `token_id`.
     ///
     /// `token_id` must exist.
@@ -86,6 +107,10 @@ pub trait InternalImpl: Internal + Storage<Data> {
     fn _emit_attribute_set_event(&self, _token_id: Id, _token_uri: URI) {}
     fn _emit_attribute_set_base_event(&self, _base_uri: Option<URI>) {}
 
+    fn _render_id(&self, token_id: &Id) -> String {
+        token_id.value().to_string()
+    }
+
     fn _set_token_uri(&mut self, token_id: Id, token_uri: URI) -> Result<(), AFT37Error> {
         aft37::Internal::_token_exists(self, &token_id)?;
         self.data().token_uris.insert(&token_id, &token_uri);
@@ -20,11 +20,11 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub use crate::traits::{
-    errors::{AFT34Error, AFT34ReceiverError},
-    types::Id,
+    errors::{AFT34Error, AFT34ReceiverError, IdConversionError, IdParseError, IdPolicyError},
+    types::{Id, IdKind, IdPolicy},
 };
 use ink::prelude::vec::Vec;
-use openbrush::traits::{AccountId, Balance};
+use openbrush::traits::{AccountId, Balance, Timestamp};
 
 #[openbrush::wrapper]
 pub type AFT34Ref = dyn AFT34;
@@ -53,7 +53,24 @@ pub trait AFT34 {
     #[ink(message)]
     fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool;
 
+    /// Returns the expiry of the approval checked by `allowance`.
+    ///
+    /// Returns `None` if there is no live approval, and `Some(None)` if the approval never expires.
+    #[ink(message)]
+    fn approval_expiry(
+        &self,
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+    ) -> Option<Option<Timestamp>>;
+
+    /// Returns the account approved for the `id` token, if any.
+    #[ink(message)]
+    fn get_approved(&self, id: Id) -> Option<AccountId>;
+
     /// Approves `operator` to withdraw the `id` token from the caller's account.
+    /// A token has at most one approved account: approving another one replaces it,
+    /// and the approval is cleared when the token is transferred or burned.
     /// If `id` is `None` approves or disapproves the operator for all tokens of the caller.
     ///
     /// On success a `Approval` event is emitted.
@@ -71,6 +88,26 @@ pub trait AFT34 {
         approved: bool,
     ) -> Result<(), AFT34Error>;
 
+    /// Approves `operator` like `approve`, until the `expires` timestamp.
+    /// The approval is ignored once the block timestamp reaches `expires`.
+    ///
+    /// On success a `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("InvalidExpiry")` error if `expires` is not in the future.
+    ///
+    /// Returns `SelfApprove` error if it is self approve.
+    ///
+    /// Returns `NotApproved` error if caller is not owner of `id`.
+    #[ink(message)]
+    fn approve_until(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        expires: Timestamp,
+    ) -> Result<(), AFT34Error>;
+
     /// Transfer approved or owned token from caller.
     ///
     /// On success a `Transfer` event is emitted.
@@ -22,6 +22,7 @@
 /// Extension of [`AFT34`] that adds enumerability of all the token ids in the contract as well
 /// as all token ids owned by each account.
 pub use crate::traits::aft34::*;
+use ink::prelude::vec::Vec;
 use openbrush::traits::AccountId;
 
 #[openbrush::wrapper]
@@ -42,4 +43,16 @@ pub trait AFT34Enumerable: AFT34 {
     /// The start index is zero.
     #[ink(message)]
     fn token_by_index(&self, index: u128) -> Result<Id, AFT34Error>;
+
+    /// Returns up to `limit` token `Id`s owned by `owner`, starting at index `offset` of its token list.
+    ///
+    /// `limit` is capped to the maximum page size of the contract.
+    #[ink(message)]
+    fn tokens_of_owner(&self, owner: AccountId, offset: u128, limit: u128) -> Vec<Id>;
+
+    /// Returns up to `limit` token `Id`s of all the tokens stored by the contract, starting at index `offset`.
+    ///
+    /// `limit` is capped to the maximum page size of the contract.
+    #[ink(message)]
+    fn tokens(&self, offset: u128, limit: u128) -> Vec<Id>;
 }
@@ -0,0 +1,80 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AFT34`] that mints tokens on redemption of vouchers signed off-chain by a minter
+pub use crate::traits::aft34::Id;
+pub use crate::traits::errors::AFT34Error;
+use openbrush::traits::{AccountId, Balance, String, Timestamp};
+
+/// Voucher signed by a minter, allowing anyone to mint `id` with the URI `uri` by paying `price`.
+///
+/// If `recipient` is set, the token can only be minted to it. The voucher can't be redeemed after
+/// `expiry`, and only once per `nonce` of its minter.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Voucher {
+    pub id: Id,
+    pub uri: String,
+    pub price: Balance,
+    pub recipient: Option<AccountId>,
+    pub expiry: Timestamp,
+    pub nonce: u64,
+}
+
+#[openbrush::wrapper]
+pub type AFT34LazyMintRef = dyn AFT34LazyMint;
+
+#[openbrush::trait_definition]
+pub trait AFT34LazyMint {
+    /// Mints the token of `voucher` to `to`, `signature` being the ECDSA signature of the voucher hash
+    /// by an account with the minter role.
+    ///
+    /// The transferred value must be the price of the voucher, it is forwarded to the minter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("VoucherExpired")` error if the voucher expired.
+    ///
+    /// Returns `Custom("InvalidRecipient")` error if the voucher is restricted to another recipient.
+    ///
+    /// Returns `Custom("InvalidSignature")` error if no signer can be recovered from `signature`.
+    ///
+    /// Returns `Custom("AC::MissingRole")` error if the signer doesn't have the minter role.
+    ///
+    /// Returns `Custom("VoucherAlreadyRedeemed")` error if the nonce of the voucher was already used.
+    ///
+    /// Returns `Custom("BadMintValue")` error if the transferred value isn't the price of the voucher.
+    #[ink(message, payable)]
+    fn redeem(
+        &mut self,
+        to: AccountId,
+        voucher: Voucher,
+        signature: [u8; 65],
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the hash of `voucher` the minters sign.
+    #[ink(message)]
+    fn voucher_hash(&self, voucher: Voucher) -> [u8; 32];
+
+    /// Returns `true` if a voucher of `minter` with `nonce` was already redeemed.
+    #[ink(message)]
+    fn is_nonce_used(&self, minter: AccountId, nonce: u64) -> bool;
+}
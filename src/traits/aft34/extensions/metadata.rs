@@ -22,6 +22,7 @@
 pub use crate::traits::aft34::Id;
 /// Metadata for AFT34
 pub use crate::traits::errors::AFT34Error;
+use ink::prelude::vec::Vec;
 use openbrush::traits::String;
 
 #[openbrush::wrapper]
@@ -34,4 +35,16 @@ pub trait AFT34Metadata {
     /// If `id` is a collection id of the token, it returns attributes for collection.
     #[ink(message)]
     fn get_attribute(&self, id: Id, key: String) -> Option<String>;
+
+    /// Returns all the `(key, value)` attributes of `id`.
+    #[ink(message)]
+    fn get_attributes(&self, id: Id) -> Vec<(String, String)>;
+
+    /// Returns the keys of all the attributes of `id`.
+    #[ink(message)]
+    fn attribute_keys(&self, id: Id) -> Vec<String>;
+
+    /// Returns `true` if the attributes of `id` can no longer be updated.
+    #[ink(message)]
+    fn is_metadata_frozen(&self, id: Id) -> bool;
 }
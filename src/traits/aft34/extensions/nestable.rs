@@ -0,0 +1,117 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+/// Extension of [`AFT34`] that lets tokens own other tokens, in the spirit of [EIP-6059](https://eips.ethereum.org/EIPS/eip-6059)
+pub use crate::traits::aft34::Id;
+pub use crate::traits::errors::AFT34Error;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{AccountId, Balance};
+
+/// Token held by a parent token: an AFT34 token or an AFT37 balance of the `contract` collection.
+///
+/// The children are held by the contract of the parent, `contract` being that contract itself
+/// for AFT34 tokens of the same collection.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Child {
+    AFT34 {
+        contract: AccountId,
+        id: Id,
+    },
+    AFT37 {
+        contract: AccountId,
+        id: Id,
+        amount: Balance,
+    },
+}
+
+#[openbrush::wrapper]
+pub type AFT34NestableRef = dyn AFT34Nestable;
+
+/// A child added by the owner of the parent token, or an account approved for it, is accepted right away.
+/// Children added by other accounts stay pending until `accept_child` is called.
+///
+/// Children move with their parent, and only the owner of the root token of a nesting can transfer them out.
+#[openbrush::trait_definition]
+pub trait AFT34Nestable {
+    /// Transfers `child` from the caller to this contract and nests it into `parent_id`.
+    ///
+    /// The contract must be approved to transfer `child` when it belongs to another collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `parent_id` does not exist.
+    ///
+    /// Returns `NotApproved` error if the caller doesn't own an AFT34 `child`.
+    ///
+    /// Returns `Custom("NestingCycle")` error if `child` is `parent_id` or one of its ancestors.
+    ///
+    /// Returns `Custom("ChildTransferFailed")` error if the transfer of `child` fails.
+    #[ink(message)]
+    fn add_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error>;
+
+    /// Accepts the pending `child` of `parent_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotApproved` error if the caller neither owns `parent_id` nor is approved for it.
+    ///
+    /// Returns `Custom("ChildNotFound")` error if `child` is not pending for `parent_id`.
+    #[ink(message)]
+    fn accept_child(&mut self, parent_id: Id, child: Child) -> Result<(), AFT34Error>;
+
+    /// Transfers `child` of `parent_id` out to `to`, rejecting it if it is still pending.
+    ///
+    /// For an AFT37 `child` only `amount` of the nested balance is transferred.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotApproved` error if the caller neither owns `parent_id` nor is approved for it.
+    ///
+    /// Returns `Custom("ChildNotFound")` error if `parent_id` doesn't hold `child`.
+    ///
+    /// Returns `Custom("ChildTransferFailed")` error if the transfer of `child` fails.
+    #[ink(message)]
+    fn transfer_child(
+        &mut self,
+        parent_id: Id,
+        child: Child,
+        to: AccountId,
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the accepted children of `parent_id`.
+    #[ink(message)]
+    fn children(&self, parent_id: Id) -> Vec<Child>;
+
+    /// Returns the children of `parent_id` waiting to be accepted.
+    #[ink(message)]
+    fn pending_children(&self, parent_id: Id) -> Vec<Child>;
+
+    /// Returns the token of this collection `id` is nested into, if any.
+    #[ink(message)]
+    fn parent_of(&self, id: Id) -> Option<Id>;
+
+    /// Returns the owner of the top-most parent of `id`, or of `id` itself if it isn't nested.
+    #[ink(message)]
+    fn root_owner_of(&self, id: Id) -> Option<AccountId>;
+}
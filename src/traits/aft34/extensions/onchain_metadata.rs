@@ -0,0 +1,42 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AFT34`] that renders the metadata of each token fully on-chain
+pub use crate::traits::aft34::Id;
+pub use crate::traits::errors::AFT34Error;
+use openbrush::traits::String;
+
+#[openbrush::wrapper]
+pub type AFT34OnchainMetadataRef = dyn AFT34OnchainMetadata;
+
+#[openbrush::trait_definition]
+pub trait AFT34OnchainMetadata {
+    /// Returns the metadata of `id` as a `data:application/json;base64,` URI.
+    ///
+    /// The JSON document is built from the attributes of the token: `name`, `description` and `image`
+    /// are rendered as top-level fields, every other attribute as an entry of the `attributes` array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    #[ink(message)]
+    fn token_uri(&self, id: Id) -> Result<String, AFT34Error>;
+}
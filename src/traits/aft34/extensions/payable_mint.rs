@@ -22,29 +22,117 @@
 pub use crate::traits::aft34::Id;
 /// Extension of [`PSP34`] that exposes the mint function
 pub use crate::traits::errors::AFT34Error;
-use ink::prelude::string::String as PreludeString;
-use openbrush::traits::{AccountId, Balance};
+use ink::prelude::{string::String as PreludeString, vec::Vec};
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// A phase of the sale, open from `start` (inclusive) to `end` (exclusive).
+///
+/// If `merkle_root` is set, only the wallets of the allowlist it commits to can mint during the phase.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct SalePhase {
+    pub price: Balance,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// Maximum amount of tokens a wallet can mint during the phase, unlimited if `None`.
+    pub max_per_wallet: Option<u64>,
+    /// Maximum amount of tokens minted during the phase, unlimited if `None`.
+    pub supply: Option<u64>,
+    pub merkle_root: Option<[u8; 32]>,
+}
 
 #[openbrush::wrapper]
 pub type AFT34PayableMintRef = dyn AFT34PayableMint;
 
 #[openbrush::trait_definition]
 pub trait AFT34PayableMint {
+    /// Mints `mint_amount` tokens to `to`, refunding any value transferred above their price.
     #[ink(message, payable)]
     fn mint(&mut self, to: AccountId, mint_amount: u64) -> Result<(), AFT34Error>;
 
+    /// Mints during an allowlisted phase, `proof` proving that the caller is part of its allowlist.
+    #[ink(message, payable)]
+    fn mint_allowlisted(
+        &mut self,
+        to: AccountId,
+        mint_amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), AFT34Error>;
+
+    /// Withdraws the native balance, and the payment token balance if any,
+    /// to the payout splits, the payout address or the contract owner.
     #[ink(message)]
     fn withdraw(&mut self) -> Result<(), AFT34Error>;
 
     #[ink(message)]
     fn set_base_uri(&mut self, uri: PreludeString) -> Result<(), AFT34Error>;
 
+    /// Sets the suffix appended to the token URIs, `None` restoring the default `.json` suffix.
+    #[ink(message)]
+    fn set_uri_suffix(&mut self, uri_suffix: Option<PreludeString>) -> Result<(), AFT34Error>;
+
+    #[ink(message)]
+    fn base_uri(&self) -> Option<PreludeString>;
+
+    /// Returns the URI of `token_id`: the base URI, followed by the rendered id and the suffix.
+    ///
+    /// Returns `None` if no base URI is set.
     #[ink(message)]
-    fn token_uri(&self, token_id: u64) -> Result<PreludeString, AFT34Error>;
+    fn token_uri(&self, token_id: Id) -> Result<Option<PreludeString>, AFT34Error>;
 
     #[ink(message)]
     fn max_supply(&self) -> u64;
 
+    /// Returns the price of the active phase, or the default price if no phase is active.
     #[ink(message)]
     fn price(&self) -> Balance;
+
+    /// Adds a phase if `phase_id` is the number of phases, otherwise replaces the phase `phase_id`.
+    #[ink(message)]
+    fn set_phase(&mut self, phase_id: u32, phase: SalePhase) -> Result<(), AFT34Error>;
+
+    #[ink(message)]
+    fn phase(&self, phase_id: u32) -> Option<SalePhase>;
+
+    #[ink(message)]
+    fn phases_count(&self) -> u32;
+
+    /// Returns the first phase open at the current block timestamp.
+    #[ink(message)]
+    fn active_phase(&self) -> Option<(u32, SalePhase)>;
+
+    /// Returns how many tokens `wallet` can still mint during the active phase.
+    ///
+    /// The allowlist of the phase is not taken into account.
+    #[ink(message)]
+    fn remaining_allocation(&self, wallet: AccountId) -> u64;
+
+    /// Sets the AFT22 token mints are paid in, or `None` to pay in the native balance.
+    ///
+    /// The price is then expressed in that token, pulled from the minter with `transfer_from`.
+    /// The balance held in the previous payment token, if any, is paid out first.
+    #[ink(message)]
+    fn set_payment_token(&mut self, payment_token: Option<AccountId>) -> Result<(), AFT34Error>;
+
+    #[ink(message)]
+    fn payment_token(&self) -> Option<AccountId>;
+
+    /// Sets the recipient of withdrawals, or `None` to pay the contract owner.
+    #[ink(message)]
+    fn set_payout_address(&mut self, payout_address: Option<AccountId>) -> Result<(), AFT34Error>;
+
+    #[ink(message)]
+    fn payout_address(&self) -> Option<AccountId>;
+
+    /// Sets the recipients withdrawals are split between, proportionally to their weight.
+    ///
+    /// Takes precedence over the payout address; an empty list disables the splits.
+    #[ink(message)]
+    fn set_payout_splits(&mut self, splits: Vec<(AccountId, u32)>) -> Result<(), AFT34Error>;
+
+    #[ink(message)]
+    fn payout_splits(&self) -> Vec<(AccountId, u32)>;
 }
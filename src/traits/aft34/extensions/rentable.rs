@@ -0,0 +1,59 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AFT34`] that lends tokens for a limited time, following [EIP-4907](https://eips.ethereum.org/EIPS/eip-4907)
+pub use crate::traits::aft34::Id;
+pub use crate::traits::errors::AFT34Error;
+use openbrush::traits::{AccountId, Timestamp};
+
+#[openbrush::wrapper]
+pub type AFT34RentableRef = dyn AFT34Rentable;
+
+/// The user of a token can use it until `expires` without owning it.
+///
+/// The user role is distinct from the ownership and the approvals, and is cleared when the token is transferred.
+#[openbrush::trait_definition]
+pub trait AFT34Rentable {
+    /// Sets `user` as the user of `id` until `expires`, a timestamp in milliseconds.
+    ///
+    /// Passing `None` as `user` clears the user of `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    ///
+    /// Returns `NotApproved` error if the caller is neither the owner of `id` nor approved for it.
+    #[ink(message)]
+    fn set_user(
+        &mut self,
+        id: Id,
+        user: Option<AccountId>,
+        expires: Timestamp,
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the user of `id`, `None` if there is none or if the rental expired.
+    #[ink(message)]
+    fn user_of(&self, id: Id) -> Option<AccountId>;
+
+    /// Returns the end of the rental of `id`, `None` if it has no user or if the rental expired.
+    #[ink(message)]
+    fn user_expires(&self, id: Id) -> Option<Timestamp>;
+}
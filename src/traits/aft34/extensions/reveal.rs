@@ -0,0 +1,89 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AFT34`] for blind drops, revealing the metadata of the collection after the mint
+pub use crate::traits::aft34::Id;
+pub use crate::traits::errors::AFT34Error;
+use openbrush::traits::String;
+
+#[openbrush::wrapper]
+pub type AFT34RevealRef = dyn AFT34Reveal;
+
+/// The metadata of the collection and the reveal seed are committed to before the mint, through a provenance
+/// hash and the Keccak256 hash of the seed.
+///
+/// Once revealed, the token `id` is mapped to the metadata id `(id - 1 + starting_offset) % collection_size + 1`,
+/// the starting offset being derived from the reveal seed and the provenance hash.
+#[openbrush::trait_definition]
+pub trait AFT34Reveal {
+    /// Reveals the collection, deriving the starting offset from `seed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("AlreadyRevealed")` error if the collection is already revealed.
+    ///
+    /// Returns `Custom("ProvenanceNotSet")` error if no provenance hash was committed.
+    ///
+    /// Returns `Custom("InvalidSeed")` error if the Keccak256 hash of `seed` is not the committed one.
+    #[ink(message)]
+    fn reveal(&mut self, seed: [u8; 32]) -> Result<(), AFT34Error>;
+
+    /// Commits to the metadata of the collection and to the reveal seed, through `seed_commitment`,
+    /// the Keccak256 hash of the seed. Can only be set once, before any token is minted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("ProvenanceAlreadySet")` error if the provenance hash is already set.
+    ///
+    /// Returns `Custom("AlreadyMinted")` error if a token is already minted.
+    #[ink(message)]
+    fn set_provenance(
+        &mut self,
+        provenance: [u8; 32],
+        seed_commitment: [u8; 32],
+    ) -> Result<(), AFT34Error>;
+
+    /// Returns the placeholder URI before the reveal, the URI of the metadata of `id` after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    #[ink(message)]
+    fn token_uri(&self, id: Id) -> Result<Option<String>, AFT34Error>;
+
+    #[ink(message)]
+    fn is_revealed(&self) -> bool;
+
+    #[ink(message)]
+    fn provenance(&self) -> Option<[u8; 32]>;
+
+    /// Returns the Keccak256 hash of the reveal seed, `None` until the provenance is committed.
+    #[ink(message)]
+    fn seed_commitment(&self) -> Option<[u8; 32]>;
+
+    /// Returns the starting offset, `None` until the collection is revealed.
+    #[ink(message)]
+    fn starting_offset(&self) -> Option<u64>;
+
+    /// Returns the metadata id `id` maps to, `None` until the collection is revealed.
+    #[ink(message)]
+    fn metadata_id(&self, id: Id) -> Result<Option<u64>, AFT34Error>;
+}
@@ -0,0 +1,39 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AFT34`] for non-transferable tokens, following [EIP-5192](https://eips.ethereum.org/EIPS/eip-5192)
+pub use crate::traits::aft34::Id;
+pub use crate::traits::errors::AFT34Error;
+
+#[openbrush::wrapper]
+pub type AFT34SoulboundRef = dyn AFT34Soulbound;
+
+/// Locked tokens cannot be moved between owners; they can still be minted and, if allowed, burned.
+#[openbrush::trait_definition]
+pub trait AFT34Soulbound {
+    /// Returns `true` if `id` is bound to its owner.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenNotExists` error if `id` does not exist.
+    #[ink(message)]
+    fn locked(&self, id: Id) -> Result<bool, AFT34Error>;
+}
@@ -33,6 +33,14 @@ pub type AFT34URIStorageRef = dyn AFT34URIStorage;
 pub trait AFT34URIStorage {
     #[ink(message)]
     fn base_uri(&self) -> Option<URI>;
+    /// Returns the URI of `token_id`.
+    ///
+    /// If no URI was set for `token_id` and the fallback is enabled, the URI is derived from
+    /// the base URI, the rendered id and the suffix.
     #[ink(message)]
     fn token_uri(&self, token_id: Id) -> Result<Option<URI>, AFT34Error>;
+
+    /// Returns `true` if the metadata of `token_id` can no longer be updated.
+    #[ink(message)]
+    fn is_metadata_frozen(&self, token_id: Id) -> bool;
 }
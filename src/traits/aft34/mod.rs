@@ -26,8 +26,14 @@ pub use aft34::*;
 pub mod extensions {
     pub mod burnable;
     pub mod enumerable;
+    pub mod lazy_mint;
     pub mod metadata;
     pub mod mintable;
+    pub mod nestable;
+    pub mod onchain_metadata;
     pub mod payable_mint;
+    pub mod rentable;
+    pub mod reveal;
+    pub mod soulbound;
     pub mod uri_storage;
 }
@@ -20,11 +20,11 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub use crate::traits::{
-    errors::{AFT37Error, AFT37ReceiverError},
-    types::Id,
+    errors::{AFT37Error, AFT37ReceiverError, IdConversionError, IdParseError, IdPolicyError},
+    types::{Id, IdKind, IdPolicy},
 };
 use ink::prelude::vec::Vec;
-use openbrush::traits::{AccountId, Balance};
+use openbrush::traits::{AccountId, Balance, Timestamp};
 
 #[openbrush::wrapper]
 pub type AFT37Ref = dyn AFT37;
@@ -51,6 +51,17 @@ pub trait AFT37 {
     #[ink(message)]
     fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> Balance;
 
+    /// Returns the expiry of the allowance returned by `allowance`.
+    ///
+    /// Returns `None` if there is no live allowance, and `Some(None)` if the allowance never expires.
+    #[ink(message)]
+    fn approval_expiry(
+        &self,
+        owner: AccountId,
+        operator: AccountId,
+        id: Option<Id>,
+    ) -> Option<Option<Timestamp>>;
+
     /// Allows `operator` to withdraw the `id` token from the caller's account
     /// multiple times, up to the `value` amount.
     /// If this function is called again it overwrites the current allowance with `value`
@@ -65,6 +76,23 @@ pub trait AFT37 {
         value: Balance,
     ) -> Result<(), AFT37Error>;
 
+    /// Allows `operator` to withdraw like `approve`, until the `expires` timestamp.
+    /// The allowance is ignored once the block timestamp reaches `expires`.
+    ///
+    /// An `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("InvalidExpiry")` error if `expires` is not in the future.
+    #[ink(message)]
+    fn approve_until(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        value: Balance,
+        expires: Timestamp,
+    ) -> Result<(), AFT37Error>;
+
     /// Transfers `value` of `id` token from `caller` to `to`
     ///
     /// On success a `TransferSingle` event is emitted.
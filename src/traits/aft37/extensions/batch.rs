@@ -45,4 +45,58 @@ pub trait AFT37Batch {
         ids_amounts: Vec<(Id, Balance)>,
         data: Vec<u8>,
     ) -> Result<(), AFT37Error>;
+
+    /// Transfers each `(to, id, amount)` entry of `transfers` from the caller.
+    ///
+    /// Transfers are grouped by recipient and a `TransferBatch` event is emitted for each recipient.
+    #[ink(message)]
+    fn batch_transfer_multi(
+        &mut self,
+        transfers: Vec<(AccountId, Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), AFT37Error>;
+
+    /// Transfers each `(to, id, amount)` entry of `transfers` from `from`.
+    ///
+    /// Transfers are grouped by recipient and a `TransferBatch` event is emitted for each recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotAllowed` error if the caller is not allowed to transfer the total amount of an id.
+    ///
+    /// Returns `InsufficientBalance` error if `from` doesn't contain enough balance.
+    ///
+    /// Returns `Custom("Overflow")` error if the amounts of an id overflow when summed.
+    ///
+    /// Returns `Custom("BatchTooLarge")` error if `transfers` exceeds the maximum batch size.
+    #[ink(message)]
+    fn batch_transfer_multi_from(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), AFT37Error>;
+
+    /// Returns the balance of each `(owner, id)` pair of `owners_ids`, in the same order.
+    ///
+    /// See [`AFT37::balance_of`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("BatchTooLarge")` error if `owners_ids` exceeds the maximum batch size.
+    #[ink(message)]
+    fn balance_of_batch(
+        &self,
+        owners_ids: Vec<(AccountId, Option<Id>)>,
+    ) -> Result<Vec<Balance>, AFT37Error>;
+
+    /// Returns the total supply of each id of `ids`, in the same order.
+    ///
+    /// See [`AFT37::total_supply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom("BatchTooLarge")` error if `ids` exceeds the maximum batch size.
+    #[ink(message)]
+    fn total_supply_batch(&self, ids: Vec<Option<Id>>) -> Result<Vec<Balance>, AFT37Error>;
 }
@@ -22,6 +22,7 @@
 /// Extension of [`AFT37`] that adds enumerability of all the token ids in the contract as well
 /// as all token ids owned by each account.
 pub use crate::traits::aft37::*;
+use ink::prelude::vec::Vec;
 use openbrush::traits::AccountId;
 
 #[openbrush::wrapper]
@@ -42,4 +43,21 @@ pub trait AFT37Enumerable: AFT37 {
     /// The start index is zero.
     #[ink(message)]
     fn token_by_index(&self, index: u128) -> Option<Id>;
+
+    /// Returns an account holding `id` at a given `index` of its holder list.
+    /// Use along with `holder_count` to enumerate all holders of `id`.
+    ///
+    /// The start index is zero.
+    #[ink(message)]
+    fn holder_by_index(&self, id: Id, index: u128) -> Option<AccountId>;
+
+    /// Returns the amount of accounts holding a non-zero balance of `id`.
+    #[ink(message)]
+    fn holder_count(&self, id: Id) -> u128;
+
+    /// Returns up to `limit` accounts holding `id`, starting at index `offset` of its holder list.
+    ///
+    /// `limit` is capped to the maximum page size of the contract.
+    #[ink(message)]
+    fn holders_of(&self, id: Id, offset: u128, limit: u128) -> Vec<AccountId>;
 }
@@ -21,6 +21,7 @@
 
 /// Metadata for AFT37
 pub use crate::traits::aft37::Id;
+use ink::prelude::vec::Vec;
 use openbrush::traits::String;
 
 #[openbrush::wrapper]
@@ -30,4 +31,12 @@ pub type AFT37MetadataRef = dyn AFT37Metadata;
 pub trait AFT37Metadata {
     #[ink(message)]
     fn get_attribute(&self, id: Id, key: String) -> Option<String>;
+
+    /// Returns all the `(key, value)` attributes of `id`.
+    #[ink(message)]
+    fn get_attributes(&self, id: Id) -> Vec<(String, String)>;
+
+    /// Returns the keys of all the attributes of `id`.
+    #[ink(message)]
+    fn attribute_keys(&self, id: Id) -> Vec<String>;
 }
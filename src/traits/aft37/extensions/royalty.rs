@@ -0,0 +1,57 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`AFT37`] that exposes royalty information for each token id
+use crate::traits::aft37::Id;
+use ink::prelude::vec::Vec;
+use openbrush::traits::{AccountId, Balance};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Receiver of a royalty and the fraction of the sale price it is owed,
+/// expressed over the fee denominator (basis points by default).
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, StorageLayout))]
+pub struct RoyaltyInfo {
+    pub receiver: AccountId,
+    pub royalty_fraction: u16,
+}
+
+#[openbrush::wrapper]
+pub type AFT37RoyaltyRef = dyn AFT37Royalty;
+
+#[openbrush::trait_definition]
+pub trait AFT37Royalty {
+    /// Returns the receiver of the royalty of `id` and the amount owed to it for a sale at `sale_price`.
+    ///
+    /// The royalty set for `id` is used if any, otherwise the collection-wide default.
+    /// Returns `None` if neither is set.
+    #[ink(message)]
+    fn royalty_info(&self, id: Id, sale_price: Balance) -> Option<(AccountId, Balance)>;
+
+    /// Returns the royalty of a sale of `id` at `sale_price` split between all its recipients.
+    ///
+    /// If no split is set for `id`, the whole amount goes to the receiver returned by `royalty_info`.
+    /// The returned amounts always add up to the amount returned by `royalty_info`.
+    #[ink(message)]
+    fn royalty_split(&self, id: Id, sale_price: Balance) -> Vec<(AccountId, Balance)>;
+}
@@ -26,6 +26,9 @@ use openbrush::traits::String;
 
 pub type URI = String;
 
+/// Placeholder of a templated base URI, substituted with the rendered id of the token.
+pub const ID_PLACEHOLDER: &str = "{id}";
+
 #[openbrush::wrapper]
 pub type AFT37URIStorageRef = dyn AFT37URIStorage;
 
@@ -33,6 +36,10 @@ pub type AFT37URIStorageRef = dyn AFT37URIStorage;
 pub trait AFT37URIStorage {
     #[ink(message)]
     fn base_uri(&self) -> Option<URI>;
+    /// Returns the URI of `token_id`.
+    ///
+    /// A URI set for `token_id` takes precedence. Otherwise, if the base URI contains the `{id}`
+    /// placeholder, it is substituted with the rendered id of the token.
     #[ink(message)]
     fn token_uri(&self, token_id: Id) -> Result<Option<URI>, AFT37Error>;
 }
@@ -26,8 +26,10 @@ pub use aft37::*;
 pub mod extensions {
     pub mod batch;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
     pub mod metadata;
     pub mod mintable;
+    pub mod royalty;
     pub mod uri_storage;
 }
@@ -19,6 +19,7 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use super::{AFT22Error, AFT37Error, IdPolicyError};
 use ink::prelude::string::String;
 use openbrush::contracts::traits::errors::{
     AccessControlError, OwnableError, PausableError, ReentrancyGuardError,
@@ -41,6 +42,10 @@ pub enum AFT34Error {
     TokenNotExists,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if the metadata of the token is frozen
+    MetadataFrozen,
+    /// Returned if the id doesn't follow the id policy of the collection
+    InvalidId(IdPolicyError),
 }
 
 impl From<OwnableError> for AFT34Error {
@@ -89,6 +94,57 @@ impl From<ReentrancyGuardError> for AFT34Error {
     }
 }
 
+impl From<AFT22Error> for AFT34Error {
+    fn from(error: AFT22Error) -> Self {
+        match error {
+            AFT22Error::Custom(message) => AFT34Error::Custom(String::from("AFT22::") + &message),
+            AFT22Error::InsufficientBalance => {
+                AFT34Error::Custom(String::from("AFT22::InsufficientBalance"))
+            }
+            AFT22Error::InsufficientAllowance => {
+                AFT34Error::Custom(String::from("AFT22::InsufficientAllowance"))
+            }
+            AFT22Error::RecipientIsNotSet => {
+                AFT34Error::Custom(String::from("AFT22::RecipientIsNotSet"))
+            }
+            AFT22Error::SenderIsNotSet => AFT34Error::Custom(String::from("AFT22::SenderIsNotSet")),
+            AFT22Error::SafeTransferCheckFailed(message) => {
+                AFT34Error::SafeTransferCheckFailed(message)
+            }
+        }
+    }
+}
+
+impl From<AFT37Error> for AFT34Error {
+    fn from(error: AFT37Error) -> Self {
+        match error {
+            AFT37Error::Custom(message) => AFT34Error::Custom(String::from("AFT37::") + &message),
+            AFT37Error::InsufficientBalance => {
+                AFT34Error::Custom(String::from("AFT37::InsufficientBalance"))
+            }
+            AFT37Error::TransferToNonSetAddress => {
+                AFT34Error::Custom(String::from("AFT37::TransferToNonSetAddress"))
+            }
+            AFT37Error::TokenNotExists => AFT34Error::Custom(String::from("AFT37::TokenNotExists")),
+            AFT37Error::NotAllowed => AFT34Error::Custom(String::from("AFT37::NotAllowed")),
+            AFT37Error::SelfApprove => AFT34Error::Custom(String::from("AFT37::SelfApprove")),
+            AFT37Error::SafeTransferCheckFailed(message) => {
+                AFT34Error::SafeTransferCheckFailed(message)
+            }
+            AFT37Error::InvalidId(error) => AFT34Error::InvalidId(error),
+            AFT37Error::MaxSupplyExceeded => {
+                AFT34Error::Custom(String::from("AFT37::MaxSupplyExceeded"))
+            }
+        }
+    }
+}
+
+impl From<IdPolicyError> for AFT34Error {
+    fn from(error: IdPolicyError) -> Self {
+        AFT34Error::InvalidId(error)
+    }
+}
+
 /// The AFT34Receiver error types.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -19,6 +19,7 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use super::IdPolicyError;
 use openbrush::contracts::traits::errors::{
     AccessControlError, OwnableError, PausableError, ReentrancyGuardError,
 };
@@ -42,6 +43,10 @@ pub enum AFT37Error {
     SelfApprove,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if the id doesn't follow the id policy of the collection
+    InvalidId(IdPolicyError),
+    /// Returned if minting would exceed the maximum supply of the token id
+    MaxSupplyExceeded,
 }
 
 impl From<OwnableError> for AFT37Error {
@@ -90,6 +95,12 @@ impl From<ReentrancyGuardError> for AFT37Error {
     }
 }
 
+impl From<IdPolicyError> for AFT37Error {
+    fn from(error: IdPolicyError) -> Self {
+        AFT37Error::InvalidId(error)
+    }
+}
+
 /// The AFT37Receiver error types.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
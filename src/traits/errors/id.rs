@@ -0,0 +1,42 @@
+// Copyright (c) 2022-2023 Allfeat labs
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Errors returned when converting an [`Id`](crate::traits::types::Id) into an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum IdConversionError {
+    /// Returned if the id is an `Id::Bytes` id.
+    NotAnInteger,
+    /// Returned if the value of the id doesn't fit in the target integer.
+    Overflow,
+}
+
+/// Errors returned when parsing an [`Id`](crate::traits::types::Id) from its textual format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum IdParseError {
+    /// Returned if the text is not of the `<variant>:<value>` form.
+    InvalidFormat,
+    /// Returned if the variant is not one of `u8`, `u16`, `u32`, `u64`, `u128` or `bytes`.
+    UnknownVariant,
+    /// Returned if the value is not a valid decimal integer of the variant, or a valid lowercase hex string for `bytes`.
+    InvalidValue,
+}
@@ -22,7 +22,11 @@
 mod aft22;
 mod aft34;
 mod aft37;
+mod id;
+mod id_policy;
 
 pub use aft22::{AFT22Error, AFT22ReceiverError, AFT22TokenTimelockError};
 pub use aft34::{AFT34Error, AFT34ReceiverError};
 pub use aft37::{AFT37Error, AFT37ReceiverError};
+pub use id::{IdConversionError, IdParseError};
+pub use id_policy::IdPolicyError;
@@ -19,6 +19,8 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::traits::errors::{IdConversionError, IdParseError, IdPolicyError};
+use core::{fmt, str::FromStr};
 use ink::prelude::vec::Vec;
 
 #[cfg(feature = "std")]
@@ -41,3 +43,297 @@ impl Default for Id {
         Self::U8(0)
     }
 }
+
+impl Id {
+    /// Returns the variant of the id, without its value.
+    pub fn kind(&self) -> IdKind {
+        match self {
+            Id::U8(_) => IdKind::U8,
+            Id::U16(_) => IdKind::U16,
+            Id::U32(_) => IdKind::U32,
+            Id::U64(_) => IdKind::U64,
+            Id::U128(_) => IdKind::U128,
+            Id::Bytes(_) => IdKind::Bytes,
+        }
+    }
+
+    /// Returns the value of an integer id, whatever its variant, or `None` for `Id::Bytes`.
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            Id::U8(value) => Some(*value as u128),
+            Id::U16(value) => Some(*value as u128),
+            Id::U32(value) => Some(*value as u128),
+            Id::U64(value) => Some(*value as u128),
+            Id::U128(value) => Some(*value),
+            Id::Bytes(_) => None,
+        }
+    }
+
+    /// Returns a displayable value of the id without its variant: the decimal value of integer ids and
+    /// the `0x` prefixed lowercase hex string of `Id::Bytes`, so that a bytes id never renders as an integer one.
+    ///
+    /// Unlike the `Display` of `Id`, `Id::U8(1)` and `Id::U16(1)` render the same, which is what token URIs expect.
+    pub fn value(&self) -> IdValue<'_> {
+        IdValue(self)
+    }
+}
+
+/// Value of an [`Id`] without its variant, returned by [`Id::value`].
+pub struct IdValue<'a>(&'a Id);
+
+impl fmt::Display for IdValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Id::U8(value) => write!(f, "{}", value),
+            Id::U16(value) => write!(f, "{}", value),
+            Id::U32(value) => write!(f, "{}", value),
+            Id::U64(value) => write!(f, "{}", value),
+            Id::U128(value) => write!(f, "{}", value),
+            Id::Bytes(bytes) => {
+                f.write_str("0x")?;
+                write_hex(f, bytes)
+            }
+        }
+    }
+}
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+}
+
+/// Formats the id as `<variant>:<value>`, e.g. `u8:1`, `u128:42` or `bytes:00ff`, keeping the variant so that
+/// [`FromStr`] gives back the very same id.
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = match self {
+            Id::U8(_) => "u8",
+            Id::U16(_) => "u16",
+            Id::U32(_) => "u32",
+            Id::U64(_) => "u64",
+            Id::U128(_) => "u128",
+            Id::Bytes(_) => "bytes",
+        };
+        write!(f, "{}:", variant)?;
+
+        match self {
+            Id::Bytes(bytes) => write_hex(f, bytes),
+            _ => write!(f, "{}", self.value()),
+        }
+    }
+}
+
+/// Parses the `<variant>:<value>` format of the `Display` of [`Id`].
+impl FromStr for Id {
+    type Err = IdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (variant, value) = s.split_once(':').ok_or(IdParseError::InvalidFormat)?;
+
+        // The variant is matched first so that an unknown variant is reported whatever its value.
+        let parse_integer: fn(&str) -> Option<Id> = match variant {
+            "u8" => |value| value.parse().ok().map(Id::U8),
+            "u16" => |value| value.parse().ok().map(Id::U16),
+            "u32" => |value| value.parse().ok().map(Id::U32),
+            "u64" => |value| value.parse().ok().map(Id::U64),
+            "u128" => |value| value.parse().ok().map(Id::U128),
+            "bytes" => return parse_hex(value).map(Id::Bytes),
+            _ => return Err(IdParseError::UnknownVariant),
+        };
+
+        // `parse` accepts a leading `+` and leading zeros, which `Display` never writes.
+        if value.is_empty()
+            || !value.bytes().all(|byte| byte.is_ascii_digit())
+            || (value.len() > 1 && value.starts_with('0'))
+        {
+            return Err(IdParseError::InvalidValue);
+        }
+
+        parse_integer(value).ok_or(IdParseError::InvalidValue)
+    }
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>, IdParseError> {
+    fn nibble(digit: u8) -> Result<u8, IdParseError> {
+        match digit {
+            b'0'..=b'9' => Ok(digit - b'0'),
+            b'a'..=b'f' => Ok(digit - b'a' + 10),
+            _ => Err(IdParseError::InvalidValue),
+        }
+    }
+
+    if value.len() % 2 != 0 {
+        return Err(IdParseError::InvalidValue);
+    }
+
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?))
+        .collect()
+}
+
+macro_rules! impl_id_conversions {
+    ($($variant:ident => $int:ty),*) => {
+        $(
+            impl From<$int> for Id {
+                fn from(value: $int) -> Self {
+                    Id::$variant(value)
+                }
+            }
+
+            /// Converts any integer id whose value fits in the integer, whatever its variant.
+            impl TryFrom<&Id> for $int {
+                type Error = IdConversionError;
+
+                fn try_from(id: &Id) -> Result<Self, Self::Error> {
+                    let value = id.as_u128().ok_or(IdConversionError::NotAnInteger)?;
+                    <$int>::try_from(value).map_err(|_| IdConversionError::Overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_id_conversions!(U8 => u8, U16 => u16, U32 => u32, U64 => u64, U128 => u128);
+
+/// Variant of an [`Id`], without its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum IdKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Bytes,
+}
+
+/// Rules the ids of a collection must follow, applied when tokens are minted.
+///
+/// The default policy accepts any id.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IdPolicy {
+    /// Variants the ids may use, any variant if `None`.
+    pub allowed_kinds: Option<&'static [IdKind]>,
+    /// Whether integer ids are stored under the smallest allowed variant able to hold their value,
+    /// so that minting `Id::U16(1)` mints `Id::U8(1)` and the two can't both be minted.
+    pub canonical_integers: bool,
+    /// Maximum length of `Id::Bytes` ids, unbounded if `None`.
+    pub max_bytes_len: Option<u32>,
+}
+
+impl IdPolicy {
+    /// Returns the id to mint for `id`, in its canonical form if `canonical_integers` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violation of the policy by the returned id.
+    pub fn normalize(&self, id: Id) -> Result<Id, IdPolicyError> {
+        let id = if self.canonical_integers {
+            self.canonical(&id).unwrap_or(id)
+        } else {
+            id
+        };
+        self.check(&id)?;
+
+        Ok(id)
+    }
+
+    /// Checks that `id` follows the policy, without normalizing it.
+    pub fn check(&self, id: &Id) -> Result<(), IdPolicyError> {
+        if !self.allows(id.kind()) {
+            return Err(IdPolicyError::VariantNotAllowed);
+        }
+
+        if let Id::Bytes(bytes) = id {
+            if self
+                .max_bytes_len
+                .map_or(false, |max| bytes.len() > max as usize)
+            {
+                return Err(IdPolicyError::BytesTooLong);
+            }
+        } else if self.canonical_integers && self.canonical(id).as_ref() != Some(id) {
+            return Err(IdPolicyError::NonCanonicalId);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if the ids may use the `kind` variant.
+    pub fn allows(&self, kind: IdKind) -> bool {
+        self.allowed_kinds
+            .map_or(true, |kinds| kinds.contains(&kind))
+    }
+
+    /// Returns the canonical form of the integer `id`, using the smallest allowed variant able to hold its value.
+    ///
+    /// Returns `None` for `Id::Bytes` or if no allowed variant can hold the value.
+    pub fn canonical(&self, id: &Id) -> Option<Id> {
+        let value = id.as_u128()?;
+
+        [
+            IdKind::U8,
+            IdKind::U16,
+            IdKind::U32,
+            IdKind::U64,
+            IdKind::U128,
+        ]
+        .into_iter()
+        .filter(|kind| self.allows(*kind))
+        .find_map(|kind| match kind {
+            IdKind::U8 => u8::try_from(value).ok().map(Id::U8),
+            IdKind::U16 => u16::try_from(value).ok().map(Id::U16),
+            IdKind::U32 => u32::try_from(value).ok().map(Id::U32),
+            IdKind::U64 => u64::try_from(value).ok().map(Id::U64),
+            IdKind::U128 => Some(Id::U128(value)),
+            IdKind::Bytes => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::prelude::string::ToString;
+
+    #[test]
+    fn id_text_format_round_trips() {
+        let ids = [
+            Id::U8(1),
+            Id::U16(1),
+            Id::U64(2),
+            Id::U128(u128::MAX),
+            Id::Bytes(vec![0x00, 0xab]),
+        ];
+        for id in ids {
+            assert_eq!(id.to_string().parse::<Id>(), Ok(id));
+        }
+
+        assert_eq!(Id::U16(1).to_string(), "u16:1");
+        assert_eq!(Id::Bytes(vec![0x00, 0xab]).to_string(), "bytes:00ab");
+        assert_eq!(Id::Bytes(vec![0x00, 0xab]).value().to_string(), "0x00ab");
+        assert_ne!(
+            Id::Bytes(vec![0x18]).value().to_string(),
+            Id::U8(18).value().to_string()
+        );
+        assert_eq!("u8:256".parse::<Id>(), Err(IdParseError::InvalidValue));
+        assert_eq!("u8:+1".parse::<Id>(), Err(IdParseError::InvalidValue));
+        assert_eq!("u8:01".parse::<Id>(), Err(IdParseError::InvalidValue));
+        assert_eq!("u8:0".parse::<Id>(), Ok(Id::U8(0)));
+        assert_eq!("bytes:0".parse::<Id>(), Err(IdParseError::InvalidValue));
+        assert_eq!("i8:1".parse::<Id>(), Err(IdParseError::UnknownVariant));
+        assert_eq!("foo:bar".parse::<Id>(), Err(IdParseError::UnknownVariant));
+        assert_eq!("1".parse::<Id>(), Err(IdParseError::InvalidFormat));
+
+        assert_eq!(Id::from(7u32), Id::U32(7));
+        assert_eq!(u8::try_from(&Id::U64(7)), Ok(7));
+        assert_eq!(
+            u8::try_from(&Id::U16(256)),
+            Err(IdConversionError::Overflow)
+        );
+        assert_eq!(
+            u128::try_from(&Id::Bytes(vec![1])),
+            Err(IdConversionError::NotAnInteger)
+        );
+    }
+}